@@ -51,6 +51,28 @@ impl Contract {
         }
     }
 
+    /// Moves reward lost when the caller was unregistered back into caller's rewards,
+    /// the caller must be registered again.
+    pub fn claim_reward_lostfound(&mut self, token_id: AccountId) -> U128 {
+        require!(self.data().state == RunningState::Running, E004_CONTRACT_PAUSED);
+
+        let farmer_id = env::predecessor_account_id();
+        let mut farmer = self.internal_unwrap_farmer(&farmer_id);
+
+        let amount = self.data_mut().rewards_lostfound.remove(&(farmer_id.clone(), token_id.clone())).unwrap_or(0);
+        require!(amount > 0, E101_INSUFFICIENT_BALANCE);
+        farmer.add_rewards(&HashMap::from([(token_id.clone(), amount)]));
+        self.internal_set_farmer(&farmer_id, farmer);
+
+        Event::RewardLostfoundClaim {
+            farmer_id: &farmer_id,
+            token_id: &token_id,
+            claim_amount: &U128(amount),
+        }
+        .emit();
+        amount.into()
+    }
+
     #[private]
     pub fn callback_post_withdraw_reward(
         &mut self,
@@ -77,9 +99,10 @@ impl Contract {
             }
             PromiseResult::Failed => {
                 // This reverts the changes from withdraw function.
+                // Storage is not checked, failing here would lose the reward record.
                 if let Some(mut farmer) = self.internal_get_farmer(&farmer_id) {
                     farmer.add_rewards(&HashMap::from([(token_id.clone(), amount)]));
-                    self.internal_set_farmer(&farmer_id, farmer);
+                    self.internal_save_farmer(&farmer_id, farmer);
     
                     Event::RewardWithdraw {
                         farmer_id: &farmer_id,
@@ -89,6 +112,11 @@ impl Contract {
                     }
                     .emit();
                 } else {
+                    // farmer has unregistered, keep the reward until claimed after re-register
+                    let key = (farmer_id.clone(), token_id.clone());
+                    let prev = self.data().rewards_lostfound.get(&key).unwrap_or(0);
+                    self.data_mut().rewards_lostfound.insert(&key, &(prev + amount));
+
                     Event::RewardLostfound {
                         farmer_id: &farmer_id,
                         token_id: &token_id,
@@ -101,3 +129,49 @@ impl Contract {
         }
     }
 }
+
+#[cfg(test)]
+mod farmer_reward_tests {
+    use super::*;
+    use crate::unit_env::*;
+    use near_sdk::test_utils::accounts;
+
+    #[test]
+    fn restore_reward_without_storage() {
+        let (mut context, mut contract) = setup_contract();
+
+        // storage deposit no longer covers the farmer
+        let mut farmer = contract.internal_unwrap_farmer(&accounts(1));
+        farmer.storage_deposit = 0;
+        contract.internal_save_farmer(&accounts(1), farmer);
+
+        set_promise_result(&mut context, PromiseResult::Failed);
+        assert!(!contract.callback_post_withdraw_reward(accounts(2), accounts(1), 100.into()));
+        assert_eq!(contract.get_farmer_reward(accounts(1), accounts(2)).0, 100);
+    }
+
+    #[test]
+    fn reward_lostfound_after_unregister() {
+        let (mut context, mut contract) = setup_contract();
+        let mut farmer = contract.internal_unwrap_farmer(&accounts(1));
+        farmer.add_rewards(&HashMap::from([(accounts(2), 100)]));
+        contract.internal_set_farmer(&accounts(1), farmer);
+
+        // farmer unregisters before the withdrawal fails
+        testing_env!(context.predecessor_account_id(accounts(1)).attached_deposit(0).build());
+        contract.withdraw_reward(accounts(2), None);
+        testing_env!(context.predecessor_account_id(accounts(1)).attached_deposit(1).build());
+        assert!(contract.storage_unregister(None));
+        set_promise_result(&mut context, PromiseResult::Failed);
+        assert!(!contract.callback_post_withdraw_reward(accounts(2), accounts(1), 100.into()));
+        assert_eq!(contract.list_rewards_lostfound(None, None), vec![(accounts(1), accounts(2), U128(100))]);
+
+        // claimed back after registered again
+        testing_env!(context.predecessor_account_id(accounts(1)).attached_deposit(d(1, 24)).build());
+        contract.storage_deposit(None, None);
+        testing_env!(context.predecessor_account_id(accounts(1)).attached_deposit(0).build());
+        assert_eq!(contract.claim_reward_lostfound(accounts(2)).0, 100);
+        assert_eq!(contract.get_farmer_reward(accounts(1), accounts(2)).0, 100);
+        assert!(contract.list_rewards_lostfound(None, None).is_empty());
+    }
+}
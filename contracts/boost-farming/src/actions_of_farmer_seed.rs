@@ -17,7 +17,7 @@ impl Contract {
         self.sync_booster_policy(&mut farmer);
         let mut seed = self.internal_unwrap_seed(&seed_id);
 
-        require!(seed.min_locking_duration_sec > 0, E300_FORBID_LOCKING);
+        require!(seed.min_locking_duration_sec > 0 && !is_nft_seed_id(&seed_id), E300_FORBID_LOCKING);
         require!(duration_sec >= seed.min_locking_duration_sec, E201_INVALID_DURATION);
         let config = self.internal_config();
         require!(duration_sec <= config.maximum_locking_duration_sec, E201_INVALID_DURATION);
@@ -31,7 +31,7 @@ impl Contract {
 
         let increased_seed_power =
             farmer_seed.free_to_lock(amount, duration_sec, &config);
-        farmer.set_seed(&seed_id, farmer_seed);
+        self.internal_set_farmer_seed(&mut farmer, &mut seed, farmer_seed);
 
         seed.total_seed_power += increased_seed_power;
 
@@ -62,6 +62,8 @@ impl Contract {
 
         let unlock_amount: Balance = unlock_amount.into();
         let withdraw_amount: Balance = withdraw_amount.into();
+        // staked nft can only be withdrawn one by one through withdraw_nft_seed
+        require!(withdraw_amount == 0 || !is_nft_seed_id(&seed_id), E606_NFT_SEED_OPERATION);
 
         let farmer_id = env::predecessor_account_id();
 
@@ -87,14 +89,12 @@ impl Contract {
             PromiseOrValue::Value(true)
         };
 
+        farmer.assert_delegation_covered(&seed_id, &farmer_seed);
+
         seed.total_seed_amount -= withdraw_amount;
         seed.total_seed_power = seed.total_seed_power - prev + farmer_seed.get_seed_power();
 
-        if farmer_seed.is_empty() {
-            farmer.remove_seed(&seed_id);
-        } else {
-            farmer.set_seed(&seed_id, farmer_seed);
-        }
+        self.internal_set_farmer_seed(&mut farmer, &mut seed, farmer_seed);
 
         self.update_impacted_seeds(&mut farmer, &seed_id);
 
@@ -135,6 +135,7 @@ impl Contract {
         let mut farmer_seed = farmer.get_seed_unwrap(&seed_id);
 
         let (reduced_seed_power, seed_slashed) = farmer_seed.unlock_to_free_with_slashed(unlock_amount, seed.slash_rate);
+        farmer.assert_delegation_covered(&seed_id, &farmer_seed);
 
         seed.total_seed_amount -= seed_slashed;
         seed.total_seed_power -= reduced_seed_power;
@@ -144,7 +145,7 @@ impl Contract {
             .seeds_slashed
             .insert(&seed_id, &(slashed_amount + seed_slashed));
 
-        farmer.set_seed(&seed_id, farmer_seed);
+        self.internal_set_farmer_seed(&mut farmer, &mut seed, farmer_seed);
 
         self.update_impacted_seeds(&mut farmer, &seed_id);
 
@@ -161,6 +162,31 @@ impl Contract {
         .emit();
     }
 
+    /// farmer withdraws all lostfound of the seed recorded for the caller, failed again would go back to lostfound
+    #[payable]
+    pub fn withdraw_lostfound(&mut self, seed_id: SeedId) -> Promise {
+        assert_one_yocto();
+        require!(self.data().state == RunningState::Running, E004_CONTRACT_PAUSED);
+
+        let farmer_id = env::predecessor_account_id();
+        // return_seed_lostfound may have taken more than what is tracked for others,
+        // so never withdraw beyond what is left in the seed's lostfound
+        let seed_amount = self.data().seeds_lostfound.get(&seed_id).unwrap_or(0);
+        let amount = self.internal_deduct_farmer_seed_lostfound(&farmer_id, &seed_id, seed_amount);
+        require!(amount > 0, E101_INSUFFICIENT_BALANCE);
+        self.data_mut().seeds_lostfound.insert(&seed_id, &(seed_amount - amount));
+
+        self.internal_seed_transfer(&farmer_id, &seed_id, amount)
+            .then(ext_self::callback_withdraw_seed_lostfound(
+                seed_id.clone(),
+                farmer_id.clone(),
+                amount.into(),
+                env::current_account_id(),
+                0,
+                GAS_FOR_RESOLVE_SEED_TRANSFER,
+            ))
+    }
+
     #[private]
     pub fn callback_withdraw_seed(&mut self, seed_id: SeedId, sender_id: AccountId, amount: U128) -> bool {
         require!(
@@ -172,10 +198,7 @@ impl Contract {
             PromiseResult::NotReady => unreachable!(),
             PromiseResult::Failed => {
                 // all seed amount go to lostfound
-                let seed_amount = self.data().seeds_lostfound.get(&seed_id).unwrap_or(0);
-                self.data_mut()
-                    .seeds_lostfound
-                    .insert(&seed_id, &(seed_amount + amount));
+                self.internal_add_seed_lostfound(&sender_id, &seed_id, amount);
 
                 Event::SeedWithdraw {
                     farmer_id: &sender_id,
@@ -208,18 +231,7 @@ impl Contract {
         seed_id: &SeedId,
         amount: Balance,
     ) -> Promise {
-        let (token, token_id) = parse_seed_id(seed_id);
-
-        if let Some(token_id) = token_id {
-            ext_multi_fungible_token::mft_transfer(
-                wrap_mft_token_id(&token_id),
-                farmer_id.clone(),
-                amount.into(),
-                None,
-                token,
-                1, // one yocto near
-                GAS_FOR_SEED_TRANSFER,
-            )
+        self.internal_seed_transfer(farmer_id, seed_id, amount)
             .then(ext_self::callback_withdraw_seed(
                 seed_id.clone(),
                 farmer_id.clone(),
@@ -228,23 +240,109 @@ impl Contract {
                 0,
                 GAS_FOR_RESOLVE_SEED_TRANSFER,
             ))
+    }
+
+    /// record failed seed withdrawal in total lostfound and farmer's lostfound
+    pub(crate) fn internal_add_seed_lostfound(&mut self, farmer_id: &AccountId, seed_id: &SeedId, amount: Balance) {
+        let seed_amount = self.data().seeds_lostfound.get(seed_id).unwrap_or(0);
+        self.data_mut()
+            .seeds_lostfound
+            .insert(seed_id, &(seed_amount + amount));
+
+        let prev_storage = env::storage_usage();
+        let mut lostfound = self.data().farmer_seeds_lostfound.get(farmer_id).unwrap_or_default();
+        *lostfound.entry(seed_id.clone()).or_insert(0) += amount;
+        self.data_mut().farmer_seeds_lostfound.insert(farmer_id, &lostfound);
+        self.internal_update_farmer_storage_usage(farmer_id, prev_storage);
+    }
+
+    /// deduct at most amount from farmer's lostfound of the seed, return the deducted amount
+    pub(crate) fn internal_deduct_farmer_seed_lostfound(&mut self, farmer_id: &AccountId, seed_id: &SeedId, amount: Balance) -> Balance {
+        let prev_storage = env::storage_usage();
+        let mut lostfound = self.data().farmer_seeds_lostfound.get(farmer_id).unwrap_or_default();
+        let tracked = lostfound.get(seed_id).cloned().unwrap_or(0);
+        let deducted = std::cmp::min(tracked, amount);
+        if tracked > deducted {
+            lostfound.insert(seed_id.clone(), tracked - deducted);
         } else {
-            ext_fungible_token::ft_transfer(
-                farmer_id.clone(),
+            lostfound.remove(seed_id);
+        }
+        if lostfound.is_empty() {
+            self.data_mut().farmer_seeds_lostfound.remove(farmer_id);
+        } else {
+            self.data_mut().farmer_seeds_lostfound.insert(farmer_id, &lostfound);
+        }
+        self.internal_update_farmer_storage_usage(farmer_id, prev_storage);
+        deducted
+    }
+
+    /// transfer seed token out through FT, MFT or NEP-245 MT interface according to seed_id
+    pub(crate) fn internal_seed_transfer(
+        &self,
+        receiver_id: &AccountId,
+        seed_id: &SeedId,
+        amount: Balance,
+    ) -> Promise {
+        require!(!is_nft_seed_id(seed_id), E606_NFT_SEED_OPERATION);
+        let (token, token_id) = parse_seed_id(seed_id);
+
+        match token_id {
+            Some(token_id) if is_mt_seed_id(seed_id) => ext_multi_token::mt_transfer(
+                receiver_id.clone(),
+                token_id,
                 amount.into(),
                 None,
                 token,
                 1, // one yocto near
                 GAS_FOR_SEED_TRANSFER,
-            )
-            .then(ext_self::callback_withdraw_seed(
-                seed_id.clone(),
-                farmer_id.clone(),
+            ),
+            Some(token_id) => ext_multi_fungible_token::mft_transfer(
+                wrap_mft_token_id(&token_id),
+                receiver_id.clone(),
                 amount.into(),
-                env::current_account_id(),
-                0,
-                GAS_FOR_RESOLVE_SEED_TRANSFER,
-            ))
+                None,
+                token,
+                1, // one yocto near
+                GAS_FOR_SEED_TRANSFER,
+            ),
+            None => ext_fungible_token::ft_transfer(
+                receiver_id.clone(),
+                amount.into(),
+                None,
+                token,
+                1, // one yocto near
+                GAS_FOR_SEED_TRANSFER,
+            ),
         }
     }
 }
+
+#[cfg(test)]
+mod farmer_seed_tests {
+    use crate::unit_env::*;
+    use near_sdk::test_utils::accounts;
+
+    #[test]
+    fn withdraw_lostfound_capped() {
+        let seed_id = "lost@0".to_string();
+        let (mut context, mut contract) = setup_contract();
+        let storage_usage = contract.internal_unwrap_farmer(&accounts(1)).storage_usage;
+        contract.internal_add_seed_lostfound(&accounts(1), &seed_id, 100);
+        contract.internal_add_seed_lostfound(&accounts(2), &seed_id, 100);
+        // lostfound of a registered farmer is counted into its storage
+        assert!(contract.internal_unwrap_farmer(&accounts(1)).storage_usage > storage_usage);
+
+        // owner returns more than tracked for accounts(1)
+        testing_env!(context.predecessor_account_id(accounts(0)).attached_deposit(1).build());
+        contract.return_seed_lostfound(accounts(1), seed_id.clone(), 150.into());
+        assert_eq!(contract.list_lostfound(None, None)[&seed_id].0, 50);
+        assert!(contract.get_farmer_lostfound(accounts(1)).is_empty());
+        assert_eq!(contract.internal_unwrap_farmer(&accounts(1)).storage_usage, storage_usage);
+
+        // accounts(2) only gets what is left, the rest stays tracked
+        testing_env!(context.predecessor_account_id(accounts(2)).attached_deposit(1).build());
+        contract.withdraw_lostfound(seed_id.clone());
+        assert_eq!(contract.list_lostfound(None, None)[&seed_id].0, 0);
+        assert_eq!(contract.get_farmer_lostfound(accounts(2))[&seed_id].0, 50);
+    }
+}
@@ -1,4 +1,5 @@
 use crate::*;
+use near_contract_standards::fungible_token::core_impl::ext_fungible_token;
 
 #[near_bindgen]
 impl Contract {
@@ -37,20 +38,7 @@ impl Contract {
         require!(self.is_owner_or_operators(), E002_NOT_ALLOWED);
         require!(self.data().state == RunningState::Running, E004_CONTRACT_PAUSED);
 
-        let farm_id = self.internal_add_farm(&seed_id, &terms);
-
-        self.assert_booster_affected_farm_num();
-
-        Event::FarmCreate {
-            caller_id: &env::predecessor_account_id(),
-            reward_token: &terms.reward_token,
-            farm_id: &farm_id,
-            daily_reward: &U128(terms.daily_reward),
-            start_at: terms.start_at,
-        }
-        .emit();
-
-        farm_id
+        self.internal_create_farm(&env::predecessor_account_id(), &seed_id, &terms)
     }
 
     /// cancel a farm before any reward deposited
@@ -69,7 +57,8 @@ impl Contract {
         .emit();
     }
 
-    /// outdate a farm to make it offline from both farmer and reward provider
+    /// outdate a farm to make it offline from both farmer and reward provider,
+    /// farmers can still claim their rewards from it during the claim grace period
     #[payable]
     pub fn remove_farm_from_seed(&mut self, farm_id: String) {
         assert_one_yocto();
@@ -79,26 +68,196 @@ impl Contract {
         let (seed_id, _) = parse_farm_id(&farm_id);
         let mut seed = self.internal_unwrap_seed(&seed_id);
 
-        let v_outdated_farm = seed.farms.remove(&farm_id).expect(E401_FARM_NOT_EXIST);
-        let mut outdated_farm = match v_outdated_farm {
-            VSeedFarm::V0(farm) => {
-                farm.into()
+        self.internal_outdate_farm(&mut seed, &farm_id);
+        self.internal_set_seed(&seed_id, seed);
+    }
+
+    /// outdate farms that have ended for longer than the grace period, anyone can call.
+    /// Legacy farms without ended_at are left to remove_farm_from_seed.
+    /// Return farm ids cleaned up in this call.
+    pub fn cleanup_ended_farms(&mut self, seed_id: SeedId) -> Vec<FarmId> {
+        require!(self.data().state == RunningState::Running, E004_CONTRACT_PAUSED);
+
+        let grace_sec = self.internal_config().farm_cleanup_grace_sec;
+        let mut seed = self.internal_unwrap_seed(&seed_id);
+
+        let mut expired_farm_ids: Vec<FarmId> = seed
+            .farms
+            .iter()
+            .filter(|(_, vfarm)| matches!(vfarm, VSeedFarm::Current(farm) if farm.is_expired(grace_sec)))
+            .map(|(farm_id, _)| farm_id.clone())
+            .collect();
+        expired_farm_ids.sort();
+        expired_farm_ids.truncate(MAX_NUM_FARMS_CLEANUP_PER_CALL);
+
+        for farm_id in expired_farm_ids.iter() {
+            self.internal_outdate_farm(&mut seed, farm_id);
+
+            Event::FarmCleanup {
+                caller_id: &env::predecessor_account_id(),
+                farm_id,
             }
-            VSeedFarm::Current(farm) => {
-                farm
+            .emit();
+        }
+        self.internal_set_seed(&seed_id, seed);
+
+        expired_farm_ids
+    }
+
+    /// deposit native NEAR as reward of a wNEAR farm,
+    /// the NEAR is wrapped into configured wNEAR contract first, and refunded if wrapping fails.
+    #[payable]
+    pub fn deposit_reward_near(&mut self, farm_id: FarmId) -> Promise {
+        let amount = env::attached_deposit();
+        require!(amount > 0, E101_INSUFFICIENT_BALANCE);
+        let wnear_id = self.internal_config().wnear_id.expect(E407_WNEAR_NOT_SET);
+        let depositor_id = env::predecessor_account_id();
+        if let Err(e) = self.internal_check_reward_deposit(&farm_id, &wnear_id) {
+            env::panic_str(e);
+        }
+
+        // batched in one receipt, registering this contract first keeps near_deposit from
+        // taking the storage fee out of the reward, the storage deposit is refunded if already registered.
+        ext_wrap_near::storage_deposit(
+            Some(env::current_account_id()),
+            Some(true),
+            wnear_id.clone(),
+            WNEAR_STORAGE_DEPOSIT,
+            GAS_FOR_WNEAR_STORAGE_DEPOSIT,
+        )
+        .function_call(
+            "near_deposit".to_string(),
+            b"{}".to_vec(),
+            amount,
+            GAS_FOR_WRAP_NEAR,
+        )
+        .then(ext_self::callback_deposit_reward_near(
+            farm_id,
+            depositor_id,
+            wnear_id,
+            U128(amount),
+            env::current_account_id(),
+            0,
+            GAS_FOR_RESOLVE_WRAP_NEAR,
+        ))
+    }
+
+    #[private]
+    pub fn callback_deposit_reward_near(&mut self, farm_id: FarmId, depositor_id: AccountId, wnear_id: AccountId, amount: U128) -> bool {
+        require!(
+            env::promise_results_count() == 1,
+            E001_PROMISE_RESULT_COUNT_INVALID
+        );
+        match env::promise_result(0) {
+            PromiseResult::NotReady => unreachable!(),
+            PromiseResult::Failed => {
+                Promise::new(depositor_id).transfer(amount.0);
+                false
             }
-        };
+            PromiseResult::Successful(_) => {
+                if let Err(e) = self.internal_check_reward_deposit(&farm_id, &wnear_id) {
+                    // the farm can not take it any more after wrapping, give the wNEAR back
+                    log!("Refund {} wNEAR to {}: {}", amount.0, depositor_id, e);
+                    ext_fungible_token::ft_transfer(
+                        depositor_id.clone(),
+                        amount,
+                        None,
+                        wnear_id.clone(),
+                        1,
+                        GAS_FOR_REWARD_TRANSFER,
+                    )
+                    .then(ext_self::callback_refund_reward_near(
+                        depositor_id,
+                        wnear_id,
+                        amount,
+                        env::current_account_id(),
+                        0,
+                        GAS_FOR_RESOLVE_REWARD_TRANSFER,
+                    ));
+                    return false;
+                }
+                let (total_amount, start_at) =
+                    self.internal_deposit_reward(&farm_id, &depositor_id, &wnear_id, amount.0);
+
+                Event::RewardDeposit {
+                    caller_id: &depositor_id,
+                    farm_id: &farm_id,
+                    deposit_amount: &amount,
+                    total_amount: &U128(total_amount),
+                    start_at,
+                }
+                .emit();
+                true
+            }
+        }
+    }
 
-        outdated_farm.finalize();
+    /// wNEAR failed to be refunded is kept as depositor's reward lostfound,
+    /// which can be claimed by claim_reward_lostfound after registered.
+    #[private]
+    pub fn callback_refund_reward_near(&mut self, depositor_id: AccountId, wnear_id: AccountId, amount: U128) -> bool {
+        require!(
+            env::promise_results_count() == 1,
+            E001_PROMISE_RESULT_COUNT_INVALID
+        );
+        match env::promise_result(0) {
+            PromiseResult::NotReady => unreachable!(),
+            PromiseResult::Successful(_) => true,
+            PromiseResult::Failed => {
+                let key = (depositor_id.clone(), wnear_id.clone());
+                let prev = self.data().rewards_lostfound.get(&key).unwrap_or(0);
+                self.data_mut().rewards_lostfound.insert(&key, &(prev + amount.0));
 
-        self.data_mut().outdated_farms.insert(&farm_id, &outdated_farm.into());
-        self.internal_set_seed(&seed_id, seed);
-        self.data_mut().farm_count -= 1;
+                Event::RewardLostfound {
+                    farmer_id: &depositor_id,
+                    token_id: &wnear_id,
+                    withdraw_amount: &amount,
+                }
+                .emit();
+                false
+            }
+        }
     }
 }
 
 impl Contract {
 
+    /// check whether internal_deposit_reward can credit the deposit without panic
+    pub(crate) fn internal_check_reward_deposit(&self, farm_id: &FarmId, reward_token: &AccountId) -> Result<(), &'static str> {
+        if self.data().state != RunningState::Running {
+            return Err(E004_CONTRACT_PAUSED);
+        }
+        let (seed_id, _) = parse_farm_id(farm_id);
+        let seed = self.internal_get_seed(&seed_id).ok_or(E301_SEED_NOT_EXIST)?;
+        let farm: SeedFarm = seed.farms.get(farm_id).ok_or(E401_FARM_NOT_EXIST)?.clone().into();
+        if &farm.terms.reward_token != reward_token {
+            return Err(E404_UNMATCHED_REWARD_TOKEN);
+        }
+        Ok(())
+    }
+
+    /// add farm to seed and emit FarmCreate, caller permission should be checked before
+    pub(crate) fn internal_create_farm(&mut self, caller_id: &AccountId, seed_id: &SeedId, terms: &FarmTerms) -> FarmId {
+        let farm_id = self.internal_add_farm(seed_id, terms);
+
+        self.assert_booster_affected_farm_num();
+
+        if self.internal_get_reward_token_metadata(&terms.reward_token).is_none() {
+            self.internal_fetch_reward_token_metadata(&terms.reward_token);
+        }
+
+        Event::FarmCreate {
+            caller_id,
+            reward_token: &terms.reward_token,
+            farm_id: &farm_id,
+            daily_reward: &U128(terms.daily_reward),
+            start_at: terms.start_at,
+        }
+        .emit();
+
+        farm_id
+    }
+
     fn internal_add_farm(&mut self, seed_id: &SeedId, terms: &FarmTerms) -> FarmId {
         if let Some(mut seed) = self.internal_get_seed(seed_id) {
             require!(
@@ -130,11 +289,12 @@ impl Contract {
         let farm: SeedFarm = vfarm.into();
         require!(farm.total_reward == 0, E403_FARM_ALREADY_DEPOSIT_REWARD);
         self.internal_set_seed(&seed_id, seed);
+        self.data_mut().farm_checkpoints.remove(farm_id);
         self.data_mut().farm_count -= 1;
         
     }
 
-    pub fn internal_deposit_reward(&mut self, farm_id: &FarmId, reward_token: &AccountId, amount: Balance) -> (Balance, u32) {
+    pub fn internal_deposit_reward(&mut self, farm_id: &FarmId, depositor_id: &AccountId, reward_token: &AccountId, amount: Balance) -> (Balance, u32) {
 
         let (seed_id, _) = parse_farm_id(farm_id);
         let mut seed = self.internal_get_seed(&seed_id).expect(E301_SEED_NOT_EXIST);    
@@ -144,13 +304,59 @@ impl Contract {
             VSeedFarm::V0(farm) => {
                 farm.add_reward(reward_token, amount)
             }
+            VSeedFarm::V1(farm) => {
+                let mut farm: SeedFarm = farm.clone().into();
+                let ret = farm.add_reward(reward_token, amount);
+                *vfarm = VSeedFarm::Current(farm);
+                ret
+            }
             VSeedFarm::Current(farm) => {
                 farm.add_reward(reward_token, amount)
             }
         };
 
         self.internal_set_seed(&seed_id, seed);
+        self.internal_record_reward_deposit(farm_id, depositor_id, amount);
 
         ret
     }
 }
+
+#[cfg(test)]
+mod seed_tests {
+    use super::*;
+    use crate::unit_env::*;
+    use near_sdk::test_utils::accounts;
+
+    #[test]
+    fn deposit_reward_near_refund() {
+        let wnear: AccountId = "wnear".parse().unwrap();
+        let (mut context, mut contract) = setup_contract();
+        testing_env!(context.predecessor_account_id(accounts(0)).attached_deposit(1).build());
+        contract.modify_wnear_id(Some(wnear.clone()));
+        contract.create_seed("wrap@0".to_string(), 18, Some(0.into()), None);
+        let farm_id = contract.create_farm("wrap@0".to_string(), FarmTerms {
+            reward_token: wnear.clone(),
+            start_at: 0,
+            daily_reward: 100,
+            options: FarmOptions::default(),
+        });
+
+        // credited
+        set_promise_result(&mut context, PromiseResult::Successful(vec![]));
+        assert!(contract.callback_deposit_reward_near(farm_id.clone(), accounts(2), wnear.clone(), 1000.into()));
+        assert_eq!(contract.get_farm(farm_id.clone()).unwrap().total_reward, 1000);
+
+        // can not be credited after wrapping, wNEAR goes back instead of panic
+        set_promise_result(&mut context, PromiseResult::Successful(vec![]));
+        assert!(!contract.callback_deposit_reward_near("wrap@0#9".to_string(), accounts(2), wnear.clone(), 1000.into()));
+        set_promise_result(&mut context, PromiseResult::Successful(vec![]));
+        assert!(!contract.callback_deposit_reward_near(farm_id.clone(), accounts(2), accounts(3), 1000.into()));
+        assert_eq!(contract.get_farm(farm_id.clone()).unwrap().total_reward, 1000);
+
+        // failed refund is kept as reward lostfound
+        set_promise_result(&mut context, PromiseResult::Failed);
+        assert!(!contract.callback_refund_reward_near(accounts(3), wnear.clone(), 99.into()));
+        assert_eq!(contract.list_rewards_lostfound(None, None), vec![(accounts(3), wnear, U128(99))]);
+    }
+}
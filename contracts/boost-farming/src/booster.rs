@@ -65,14 +65,12 @@ impl Contract {
     /// generate booster ratios map for a given seed
     /// booster-ratio = ((booster_balance as f64) / (booster_base as f64)).log(log_base as f64)
     /// where log_base if from Config.global_booster_seeds.get(seed_id).unwrap().get(self.seed_id).unwrap()
+    /// and booster_balance takes booster delegations into account, see Farmer::get_booster_balance
     pub fn gen_booster_ratios(&self, seed_id: &SeedId, farmer: &Farmer) -> HashMap<SeedId, f64> {
         let mut ratios = HashMap::new();
         let log_bases = self.internal_config().get_boosters_from_seed(seed_id);
         for (booster, booster_decimal, log_base, boost_suppress_factor) in &log_bases {
-            let booster_balance = farmer
-                .get_seed(booster)
-                .map(|v| v.get_basic_seed_power())
-                .unwrap_or(0_u128);
+            let booster_balance = farmer.get_booster_balance(booster);
             if booster_balance > 0 && log_base > &0 {
                 let booster_base = 10u128.pow(*booster_decimal);
                 let booster_amount = booster_balance as f64 / booster_base as f64 / *boost_suppress_factor as f64;
@@ -110,7 +108,14 @@ impl Contract {
                 let mut booster_seed = self.internal_unwrap_seed(booster_seed_id);
 
                 let prev = farmer_seed.get_seed_power();
+                let is_seed_farmer = self.internal_is_seed_farmer(&farmer.farmer_id, booster_seed_id);
+                if is_seed_farmer {
+                    booster_seed.sub_farmer_stats(&farmer_seed);
+                }
                 farmer_seed.sync_booster_policy(&config);
+                if is_seed_farmer {
+                    booster_seed.add_farmer_stats(&farmer_seed);
+                }
                 let next = farmer_seed.get_seed_power();
                 farmer.set_seed(booster_seed_id, farmer_seed);
 
@@ -0,0 +1,112 @@
+use crate::*;
+
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Clone)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug, Deserialize))]
+#[serde(crate = "near_sdk::serde")]
+pub struct BoosterDelegation {
+    #[serde(with = "u128_dec_format")]
+    pub amount: Balance,
+    /// When the delegation was created or last increased in nanoseconds.
+    #[serde(with = "u64_dec_format")]
+    pub delegated_at: Timestamp,
+}
+
+#[near_bindgen]
+impl Contract {
+    /// lend booster balance, counted in basic seed power of the booster seed, to another registered farmer,
+    /// the staked power covering it can not be unlocked or withdrawn until the delegation is revoked.
+    #[payable]
+    pub fn delegate_booster(&mut self, booster_id: SeedId, receiver_id: AccountId, amount: U128) {
+        assert_one_yocto();
+        require!(self.data().state == RunningState::Running, E004_CONTRACT_PAUSED);
+        require!(
+            self.internal_config().booster_seeds.contains_key(&booster_id),
+            E208_NOT_BOOSTER_SEED
+        );
+
+        let farmer_id = env::predecessor_account_id();
+        require!(farmer_id != receiver_id, E209_FORBID_SELF_DELEGATION);
+        let amount: Balance = amount.into();
+        require!(amount > 0, E101_INSUFFICIENT_BALANCE);
+
+        let mut farmer = self.internal_unwrap_farmer(&farmer_id);
+        let mut receiver = self.internal_unwrap_farmer(&receiver_id);
+        self.sync_booster_policy(&mut farmer);
+        self.sync_booster_policy(&mut receiver);
+
+        // booster balance is counted in basic seed power, so is the delegation
+        let staked_power = farmer
+            .get_seed(&booster_id)
+            .map(|v| v.get_basic_seed_power())
+            .unwrap_or(0_u128);
+        require!(
+            farmer.get_delegated_out(&booster_id) + amount <= staked_power,
+            E210_EXCEED_DELEGATABLE_AMOUNT
+        );
+
+        farmer.add_delegation_out(&booster_id, &receiver_id, amount);
+        receiver.add_delegation_in(&booster_id, amount);
+
+        self.update_impacted_seeds(&mut farmer, &booster_id);
+        self.update_impacted_seeds(&mut receiver, &booster_id);
+
+        self.internal_set_farmer(&farmer_id, farmer);
+        // the delegation entry grows receiver's storage, which must be covered by receiver's own deposit
+        let receiver = self.internal_save_farmer(&receiver_id, receiver);
+        require!(receiver.is_storage_covered(), E106_RECEIVER_INSUFFICIENT_STORAGE);
+
+        Event::BoosterDelegate {
+            farmer_id: &farmer_id,
+            booster_id: &booster_id,
+            receiver_id: &receiver_id,
+            amount: &U128(amount),
+        }
+        .emit();
+    }
+
+    /// take back all booster lent to the receiver,
+    /// only allowed after the cooldown since the delegation was last increased.
+    #[payable]
+    pub fn revoke_booster_delegation(&mut self, booster_id: SeedId, receiver_id: AccountId) {
+        assert_one_yocto();
+        require!(self.data().state == RunningState::Running, E004_CONTRACT_PAUSED);
+
+        let farmer_id = env::predecessor_account_id();
+
+        let mut farmer = self.internal_unwrap_farmer(&farmer_id);
+        let delegation = farmer
+            .booster_delegations_out
+            .get(&booster_id)
+            .and_then(|delegations| delegations.get(&receiver_id))
+            .cloned()
+            .expect(E211_DELEGATION_NOT_EXIST);
+        let cooldown_sec = self.internal_config().booster_delegation_cooldown_sec;
+        require!(
+            env::block_timestamp() >= delegation.delegated_at + to_nano(cooldown_sec),
+            E212_DELEGATION_IN_COOLDOWN
+        );
+
+        // receiver can not unregister while holding delegations
+        let mut receiver = self.internal_unwrap_farmer(&receiver_id);
+        self.sync_booster_policy(&mut farmer);
+        self.sync_booster_policy(&mut receiver);
+
+        farmer.remove_delegation_out(&booster_id, &receiver_id);
+        receiver.sub_delegation_in(&booster_id, delegation.amount);
+
+        self.update_impacted_seeds(&mut farmer, &booster_id);
+        self.update_impacted_seeds(&mut receiver, &booster_id);
+
+        self.internal_set_farmer(&farmer_id, farmer);
+        // revoking only shrinks receiver, it must not be blocked by receiver's storage
+        self.internal_save_farmer(&receiver_id, receiver);
+
+        Event::BoosterRevoke {
+            farmer_id: &farmer_id,
+            booster_id: &booster_id,
+            receiver_id: &receiver_id,
+            amount: &U128(delegation.amount),
+        }
+        .emit();
+    }
+}
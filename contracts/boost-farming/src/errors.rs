@@ -9,12 +9,15 @@ pub const E006_NOT_IMPLEMENTED: &str = "E006: not implemented";
 pub const E007_INVALID_OPERATOR: &str = "E007: invalid operator";
 pub const E008_ALREADY_ACCEPTED: &str = "E008: already accepted";
 pub const E009_EXISTING_OPERATOR: &str = "E009: operator already exists";
+pub const E010_EXCEED_SOLVENCY_CHECK_NUM: &str = "E010: exceed max solvency check num in one call";
 
 pub const E100_ACC_NOT_REGISTERED: &str = "E100: account not registered";
 pub const E101_INSUFFICIENT_BALANCE: &str = "E101: insufficient balance";
 pub const E102_INSUFFICIENT_STORAGE: &str = "E102: insufficient storage";
 pub const E103_STILL_HAS_REWARD: &str = "E103: still has reward";
 pub const E104_STILL_HAS_SEED: &str = "E104: still has seed";
+pub const E105_STILL_HAS_DELEGATION: &str = "E105: still has booster delegation";
+pub const E106_RECEIVER_INSUFFICIENT_STORAGE: &str = "E106: receiver has insufficient storage";
 
 pub const E200_INVALID_RATIO: &str = "E200: invalid ratio";
 pub const E201_INVALID_DURATION: &str = "E201: invalid duration";
@@ -24,6 +27,12 @@ pub const E204_EXCEED_SEED_NUM_IN_BOOSTER: &str = "E204: exceed max seed num in
 pub const E205_INVALID_SLASH_RATE: &str = "E205: invalid slash rate";
 pub const E206_INVALID_BOOST_SUPPRESS_FACTOR: &str = "E206: The boost_suppress_factor must be greater than 0";
 pub const E207_FORBID_BOOST_BOOSTER_SEED: &str = "E207: boost booster seed is forbidden";
+pub const E208_NOT_BOOSTER_SEED: &str = "E208: seed is not a booster";
+pub const E209_FORBID_SELF_DELEGATION: &str = "E209: self delegation is forbidden";
+pub const E210_EXCEED_DELEGATABLE_AMOUNT: &str = "E210: exceed delegatable booster amount";
+pub const E211_DELEGATION_NOT_EXIST: &str = "E211: delegation not exist";
+pub const E212_DELEGATION_IN_COOLDOWN: &str = "E212: delegation still in cooldown";
+pub const E213_INVALID_CHECKPOINT_NUM: &str = "E213: invalid farm checkpoint num";
 
 pub const E300_FORBID_LOCKING: &str = "E300: locking on this seed is forbidden";
 pub const E301_SEED_NOT_EXIST: &str = "E301: seed not exist";
@@ -41,10 +50,25 @@ pub const E403_FARM_ALREADY_DEPOSIT_REWARD: &str = "E403: farm can not be cancel
 pub const E404_UNMATCHED_REWARD_TOKEN: &str = "E404: reward token does NOT match";
 pub const E405_FARM_NOT_ENDED: &str = "E405: farm not ended";
 pub const E406_INVALID_FARM_ID: &str = "E406: invalid farm id";
+pub const E407_WNEAR_NOT_SET: &str = "E407: wnear contract not set";
 // pub const E402_FARM_ALREADY_EXIST: &str = "E402: farm already exist";
 
 pub const E500_INVALID_MSG: &str = "E500: invalid msg";
 pub const E501_UNEXPECTED_MSG: &str = "E501: unexpected msg";
+pub const E502_INVALID_SHADOW_ID: &str = "E502: invalid shadow_id";
 
 pub const E600_MFT_INVALID_TOKEN_ID: &str = "E600: MFT token_id is invalid";
-pub const E601_MFT_CAN_NOT_BE_REWARD: &str = "E601: MFT can NOT be reward token";
\ No newline at end of file
+pub const E601_MFT_CAN_NOT_BE_REWARD: &str = "E601: MFT can NOT be reward token";
+pub const E602_MT_INVALID_TRANSFER: &str = "E602: MT transfer args are invalid";
+pub const E603_NFT_VALUATION_NOT_SET: &str = "E603: nft valuation of this seed is not set";
+pub const E604_NFT_ALREADY_STAKED: &str = "E604: nft already staked";
+pub const E605_NFT_NOT_STAKED: &str = "E605: nft not staked";
+pub const E606_NFT_SEED_OPERATION: &str = "E606: operation not supported by nft seed";
+pub const E607_NFT_NOT_IN_LOSTFOUND: &str = "E607: nft not in lostfound";
+pub const E608_MT_CAN_NOT_BE_REWARD: &str = "E608: MT can NOT be reward token";
+pub const E700_SHADOW_PROVIDER_NOT_EXIST: &str = "E700: shadow provider not exist";
+pub const E701_INVALID_SHADOW_ID: &str = "E701: invalid shadow id";
+pub const E702_SHADOW_SEED_NOT_ALLOWED: &str = "E702: seed not allowed for this shadow provider";
+pub const E703_SHADOW_SYNC_FAILED: &str = "E703: failed to fetch shadow records";
+pub const E704_SHADOW_NOT_CAST_BY_PROVIDER: &str = "E704: shadow not cast by this provider";
+pub const E705_SHADOW_PROVIDER_STILL_ACTIVE: &str = "E705: shadow provider still active";
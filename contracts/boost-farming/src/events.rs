@@ -31,6 +31,10 @@ pub enum Event<'a> {
         caller_id: &'a AccountId,
         farm_id: &'a String,
     },
+    FarmCleanup {
+        caller_id: &'a AccountId,
+        farm_id: &'a String,
+    },
     RewardDeposit {
         caller_id: &'a AccountId,
         farm_id: &'a String,
@@ -77,6 +81,12 @@ pub enum Event<'a> {
         withdraw_amount: &'a U128,
         success: bool,
     },
+    SeedWithdrawRegisterFee {
+        owner_id: &'a AccountId,
+        seed_id: &'a String,
+        withdraw_amount: &'a U128,
+        success: bool,
+    },
     RewardWithdraw {
         farmer_id: &'a AccountId,
         token_id: &'a AccountId,
@@ -88,20 +98,28 @@ pub enum Event<'a> {
         token_id: &'a AccountId,
         withdraw_amount: &'a U128,
     },
+    RewardLostfoundClaim {
+        farmer_id: &'a AccountId,
+        token_id: &'a AccountId,
+        claim_amount: &'a U128,
+    },
     RewardWithdrawBeneficiary {
         owner_id: &'a AccountId,
+        receiver_id: &'a AccountId,
         farm_id: &'a String,
         withdraw_amount: &'a U128,
         success: bool,
     },
     RewardWithdrawOutdatedBeneficiary {
         owner_id: &'a AccountId,
+        receiver_id: &'a AccountId,
         farm_id: &'a String,
         withdraw_amount: &'a U128,
         success: bool,
     },
     RewardWithdrawUndistributed {
         owner_id: &'a AccountId,
+        receiver_id: &'a AccountId,
         farm_id: &'a String,
         withdraw_amount: &'a U128,
         success: bool,
@@ -138,6 +156,72 @@ pub enum Event<'a> {
     RemoveOperators {
         operators: &'a Vec<AccountId>
     },
+    BoosterDelegate {
+        farmer_id: &'a AccountId,
+        booster_id: &'a String,
+        receiver_id: &'a AccountId,
+        amount: &'a U128,
+    },
+    BoosterRevoke {
+        farmer_id: &'a AccountId,
+        booster_id: &'a String,
+        receiver_id: &'a AccountId,
+        amount: &'a U128,
+    },
+    SetShadowProvider {
+        provider_id: &'a AccountId,
+        shadow_prefix: &'a String,
+        allowed_seeds: &'a Vec<String>,
+    },
+    RemoveShadowProvider {
+        provider_id: &'a AccountId,
+    },
+    ShadowSeedSync {
+        farmer_id: &'a AccountId,
+        seed_id: &'a String,
+        prev_amount: &'a U128,
+        current_amount: &'a U128,
+    },
+    SetFarmRefundAccount {
+        caller_id: &'a AccountId,
+        farm_id: &'a String,
+        refund_account: Option<&'a AccountId>,
+    },
+    RewardTokenMetadataUpdate {
+        token_id: &'a AccountId,
+        symbol: &'a String,
+        decimals: u8,
+    },
+    FarmerAutoRegister {
+        farmer_id: &'a AccountId,
+        seed_id: &'a String,
+        register_fee: &'a U128,
+    },
+    NftSeedDeposit {
+        farmer_id: &'a AccountId,
+        seed_id: &'a String,
+        token_id: &'a str,
+        deposit_amount: &'a U128,
+        increased_power: &'a U128,
+    },
+    NftSeedWithdraw {
+        farmer_id: &'a AccountId,
+        seed_id: &'a String,
+        token_id: &'a str,
+        success: bool,
+    },
+    SolvencyCheck {
+        token_key: &'a String,
+        expected: &'a U128,
+        /// None if the balance query failed
+        actual: Option<&'a U128>,
+        /// balance beyond the liability, e.g. tokens sent in directly, informational only
+        surplus: &'a U128,
+        /// true only when actual balance is less than the liability
+        insolvent: bool,
+        /// balance query failed, so solvency of the token is unknown
+        failed: bool,
+    },
 }
 
 impl Event<'_> {
@@ -270,6 +354,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn event_seed_withdraw_register_fee() {
+        let owner_id = &alice();
+        let seed_id = &"fakeseed".to_string();
+        let withdraw_amount = &U128(100);
+        let success = true;
+        Event::SeedWithdrawRegisterFee { owner_id, seed_id, withdraw_amount, success }.emit();
+        assert_eq!(
+            test_utils::get_logs()[0],
+            r#"EVENT_JSON:{"standard":"ref-farming","version":"1.0.0","event":"seed_withdraw_register_fee","data":[{"owner_id":"alice","seed_id":"fakeseed","withdraw_amount":"100","success":true}]}"#
+        );
+    }
+
     #[test]
     fn event_reward_withdraw() {
         let farmer_id = &alice();
@@ -295,6 +392,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn event_reward_lostfound_claim() {
+        let farmer_id = &alice();
+        let token_id = &bob();
+        let claim_amount = &U128(100);
+        Event::RewardLostfoundClaim { farmer_id, token_id, claim_amount }.emit();
+        assert_eq!(
+            test_utils::get_logs()[0],
+            r#"EVENT_JSON:{"standard":"ref-farming","version":"1.0.0","event":"reward_lostfound_claim","data":[{"farmer_id":"alice","token_id":"bob","claim_amount":"100"}]}"#
+        );
+    }
+
+    #[test]
+    fn event_solvency_check() {
+        let token_key = &"fakeseed".to_string();
+        let expected = &U128(100);
+        let actual = Some(&U128(99));
+        let surplus = &U128(0);
+        let insolvent = true;
+        let failed = false;
+        Event::SolvencyCheck { token_key, expected, actual, surplus, insolvent, failed }.emit();
+        assert_eq!(
+            test_utils::get_logs()[0],
+            r#"EVENT_JSON:{"standard":"ref-farming","version":"1.0.0","event":"solvency_check","data":[{"token_key":"fakeseed","expected":"100","actual":"99","surplus":"0","insolvent":true,"failed":false}]}"#
+        );
+        Event::SolvencyCheck { token_key, expected, actual: None, surplus, insolvent: false, failed: true }.emit();
+        assert_eq!(
+            test_utils::get_logs()[1],
+            r#"EVENT_JSON:{"standard":"ref-farming","version":"1.0.0","event":"solvency_check","data":[{"token_key":"fakeseed","expected":"100","actual":null,"surplus":"0","insolvent":false,"failed":true}]}"#
+        );
+    }
+
     #[test]
     fn event_seed_deposit() {
         let farmer_id = &alice();
@@ -0,0 +1,98 @@
+use crate::*;
+
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Clone)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug, Deserialize))]
+#[serde(crate = "near_sdk::serde")]
+pub struct FarmCheckpoint {
+    #[serde(with = "u64_dec_format")]
+    pub timestamp: Timestamp,
+    pub rps: BigDecimal,
+    #[serde(with = "u128_dec_format")]
+    pub total_seed_power: Balance,
+    #[serde(with = "u128_dec_format")]
+    pub distributed_reward: Balance,
+}
+
+/// Ring buffer of checkpoints, the oldest one is overwritten when full.
+#[derive(BorshSerialize, BorshDeserialize, Default)]
+pub struct FarmCheckpoints {
+    /// position of the oldest checkpoint once the buffer is full
+    pub next_index: u32,
+    pub checkpoints: Vec<FarmCheckpoint>,
+}
+
+impl FarmCheckpoints {
+    pub fn latest(&self) -> Option<&FarmCheckpoint> {
+        if self.next_index == 0 {
+            self.checkpoints.last()
+        } else {
+            self.checkpoints.get(self.next_index as usize - 1)
+        }
+    }
+
+    pub fn push(&mut self, checkpoint: FarmCheckpoint, capacity: u32) {
+        let capacity = capacity as usize;
+        if self.checkpoints.len() != capacity {
+            // capacity changed, put back in time order before resizing
+            self.checkpoints.rotate_left(self.next_index as usize);
+            self.next_index = 0;
+            if self.checkpoints.len() > capacity {
+                self.checkpoints.drain(..self.checkpoints.len() + 1 - capacity);
+            }
+        }
+        if self.checkpoints.len() < capacity {
+            self.checkpoints.push(checkpoint);
+        } else {
+            self.checkpoints[self.next_index as usize] = checkpoint;
+            self.next_index = ((self.next_index as usize + 1) % capacity) as u32;
+        }
+    }
+
+    /// checkpoints in time order
+    pub fn to_vec(&self) -> Vec<FarmCheckpoint> {
+        let mut ret = self.checkpoints.clone();
+        ret.rotate_left(self.next_index as usize);
+        ret
+    }
+}
+
+impl Contract {
+    pub fn internal_record_farm_checkpoints(&mut self, seed: &Seed) {
+        if seed.farms.is_empty() {
+            return;
+        }
+        let config = self.internal_config();
+        let (interval_sec, max_num) = (config.farm_checkpoint_interval_sec, config.max_num_farm_checkpoints);
+        if interval_sec == 0 || max_num == 0 {
+            return;
+        }
+        let timestamp = env::block_timestamp();
+        for (farm_id, vfarm) in &seed.farms {
+            let (rps, distributed_reward, has_ended) = match vfarm {
+                VSeedFarm::V0(farm) => (farm.rps, farm.distributed_reward, matches!(farm.status, Some(FarmStatus::Ended))),
+                VSeedFarm::V1(farm) => (farm.rps, farm.distributed_reward, matches!(farm.status, Some(FarmStatus::Ended))),
+                VSeedFarm::Current(farm) => (farm.rps, farm.distributed_reward, matches!(farm.status, Some(FarmStatus::Ended))),
+            };
+            if has_ended {
+                continue;
+            }
+            let mut farm_checkpoints = self.data().farm_checkpoints.get(farm_id).unwrap_or_default();
+            let is_due = farm_checkpoints
+                .latest()
+                .map(|c| c.timestamp + to_nano(interval_sec) <= timestamp)
+                .unwrap_or(true);
+            if is_due {
+                farm_checkpoints.push(
+                    FarmCheckpoint {
+                        timestamp,
+                        rps,
+                        total_seed_power: seed.total_seed_power,
+                        distributed_reward,
+                    },
+                    max_num,
+                );
+                self.data_mut().farm_checkpoints.insert(farm_id, &farm_checkpoints);
+            }
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use crate::*;
+use near_contract_standards::fungible_token::core_impl::ext_fungible_token;
+use near_sdk::Gas;
+
+/// Who funded a farm and where its leftovers should go.
+#[derive(BorshSerialize, BorshDeserialize, Default)]
+pub struct FarmRefundInfo {
+    /// reward amount deposited by each account
+    pub depositors: HashMap<AccountId, Balance>,
+    /// if set, all leftovers go to this account instead of depositors
+    pub refund_account: Option<AccountId>,
+}
+
+impl FarmRefundInfo {
+    /// only the first MAX_NUM_DEPOSITORS_PER_FARM depositors are recorded,
+    /// deposits from others are accepted but follow the recorded depositors' share
+    pub fn add_deposit(&mut self, depositor_id: &AccountId, amount: Balance) {
+        if let Some(deposit) = self.depositors.get_mut(depositor_id) {
+            *deposit += amount;
+        } else if self.depositors.len() < MAX_NUM_DEPOSITORS_PER_FARM {
+            self.depositors.insert(depositor_id.clone(), amount);
+        }
+    }
+
+    /// deduct leftovers paid out to a depositor, so later splits follow what is left
+    pub fn sub_deposit(&mut self, depositor_id: &AccountId, amount: Balance) {
+        if let Some(deposit) = self.depositors.get_mut(depositor_id) {
+            *deposit = deposit.saturating_sub(amount);
+            if *deposit == 0 {
+                self.depositors.remove(depositor_id);
+            }
+        }
+    }
+
+    /// split amount among receivers, depositors share it in proportion to their deposits,
+    /// the rounding dust goes to the last depositor. Falls back to owner_id if nobody deposited.
+    pub fn split_refund(&self, amount: Balance, owner_id: &AccountId) -> Vec<(AccountId, Balance)> {
+        if let Some(refund_account) = &self.refund_account {
+            return vec![(refund_account.clone(), amount)];
+        }
+        let total_deposit: Balance = self.depositors.values().sum();
+        if total_deposit == 0 {
+            return vec![(owner_id.clone(), amount)];
+        }
+
+        let mut depositors: Vec<(&AccountId, &Balance)> = self.depositors.iter().collect();
+        depositors.sort_by(|a, b| a.0.cmp(b.0));
+        let last = depositors.len() - 1;
+        let mut remain = amount;
+        let mut ret = vec![];
+        for (index, (depositor_id, deposit)) in depositors.into_iter().enumerate() {
+            let share = if index == last {
+                remain
+            } else {
+                u128_ratio(amount, *deposit, total_deposit)
+            };
+            if share > 0 {
+                ret.push((depositor_id.clone(), share));
+                remain -= share;
+            }
+        }
+        ret
+    }
+}
+
+impl Contract {
+    pub fn internal_record_reward_deposit(&mut self, farm_id: &FarmId, depositor_id: &AccountId, amount: Balance) {
+        let mut refund_info = self.data().farm_refunds.get(farm_id).unwrap_or_default();
+        refund_info.add_deposit(depositor_id, amount);
+        self.data_mut().farm_refunds.insert(farm_id, &refund_info);
+    }
+
+    pub fn internal_deduct_reward_deposit(&mut self, farm_id: &FarmId, depositor_id: &AccountId, amount: Balance) {
+        if let Some(mut refund_info) = self.data().farm_refunds.get(farm_id) {
+            refund_info.sub_deposit(depositor_id, amount);
+            self.data_mut().farm_refunds.insert(farm_id, &refund_info);
+        }
+    }
+
+    pub fn internal_get_refund_receivers(&self, farm_id: &FarmId, amount: Balance) -> Vec<(AccountId, Balance)> {
+        self.data()
+            .farm_refunds
+            .get(farm_id)
+            .unwrap_or_default()
+            .split_refund(amount, &self.data().owner_id)
+    }
+
+    /// beneficiary amount goes to beneficiary_id of the farm if set, otherwise to refund receivers
+    pub fn internal_get_beneficiary_receivers(&self, farm: &SeedFarm, amount: Balance) -> Vec<(AccountId, Balance)> {
+        if let Some(beneficiary_id) = &farm.terms.options.beneficiary_id {
+            vec![(beneficiary_id.clone(), amount)]
+        } else {
+            self.internal_get_refund_receivers(&farm.farm_id, amount)
+        }
+    }
+
+    /// transfer reward token to each receiver, every transfer has its own callback to handle failure
+    pub fn internal_transfer_refunds<F>(
+        &self,
+        farm_id: &FarmId,
+        reward_token: &AccountId,
+        receivers: Vec<(AccountId, Balance)>,
+        callback: F,
+    ) -> Promise
+    where
+        F: Fn(FarmId, AccountId, U128, AccountId, Balance, Gas) -> Promise,
+    {
+        receivers
+            .into_iter()
+            .map(|(receiver_id, amount)| {
+                ext_fungible_token::ft_transfer(
+                    receiver_id.clone(),
+                    amount.into(),
+                    None,
+                    reward_token.clone(),
+                    1, // one yocto near
+                    GAS_FOR_REWARD_TRANSFER,
+                )
+                .then(callback(
+                    farm_id.clone(),
+                    receiver_id,
+                    amount.into(),
+                    env::current_account_id(),
+                    0,
+                    GAS_FOR_RESOLVE_REWARD_TRANSFER,
+                ))
+            })
+            .reduce(|acc, p| acc.and(p))
+            .unwrap()
+    }
+}
+
+#[near_bindgen]
+impl Contract {
+    /// set an account to receive all leftovers of the farm, None means refund to depositors.
+    #[payable]
+    pub fn set_farm_refund_account(&mut self, farm_id: FarmId, refund_account: Option<AccountId>) {
+        assert_one_yocto();
+        require!(self.is_owner_or_operators(), E002_NOT_ALLOWED);
+
+        let (seed_id, _) = parse_farm_id(&farm_id);
+        let is_active_farm = self
+            .internal_get_seed(&seed_id)
+            .map(|seed| seed.farms.contains_key(&farm_id))
+            .unwrap_or(false);
+        require!(
+            is_active_farm || self.data().outdated_farms.get(&farm_id).is_some(),
+            E401_FARM_NOT_EXIST
+        );
+
+        let mut refund_info = self.data().farm_refunds.get(&farm_id).unwrap_or_default();
+        refund_info.refund_account = refund_account.clone();
+        self.data_mut().farm_refunds.insert(&farm_id, &refund_info);
+
+        Event::SetFarmRefundAccount {
+            caller_id: &env::predecessor_account_id(),
+            farm_id: &farm_id,
+            refund_account: refund_account.as_ref(),
+        }
+        .emit();
+    }
+}
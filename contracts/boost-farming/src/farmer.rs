@@ -14,12 +14,25 @@ pub struct Farmer {
     pub seeds: UnorderedMap<SeedId, FarmerSeedOld>,
     #[serde(skip)]
     pub vseeds: UnorderedMap<SeedId, VFarmerSeed>,
+    /// Booster amount lent to other farmers, <booster_id, <receiver_id, delegation>>
+    pub booster_delegations_out: HashMap<SeedId, HashMap<AccountId, BoosterDelegation>>,
+    /// Booster amount lent by other farmers, <booster_id, amount>
+    pub booster_delegations_in: HashMap<SeedId, Balance>,
+    /// NEAR deposited for storage of this farmer
+    #[serde(with = "u128_dec_format")]
+    pub storage_deposit: Balance,
+    /// bytes occupied by this farmer, counted since storage tracking was introduced
+    #[serde(with = "u64_dec_format")]
+    pub storage_usage: StorageUsage,
+    /// registration fee was paid by farmer_sponsor_pool, so it goes back to the pool on unregister
+    pub sponsored_by_pool: bool,
 }
 
 #[derive(BorshSerialize, BorshDeserialize)]
 pub enum VFarmer {
     V0(FarmerV0),
     V1(FarmerV1),
+    V2(FarmerV2),
     Current(Farmer),
 }
 
@@ -28,6 +41,7 @@ impl From<VFarmer> for Farmer {
         match v {
             VFarmer::V0(c) => c.into(),
             VFarmer::V1(c) => c.into(),
+            VFarmer::V2(c) => c.into(),
             VFarmer::Current(c) => c,
         }
     }
@@ -51,8 +65,63 @@ impl Farmer {
             vseeds: UnorderedMap::new(StorageKeys::VFarmerSeed {
                 account_id: farmer_id.clone(),
             }),
+            booster_delegations_out: HashMap::new(),
+            booster_delegations_in: HashMap::new(),
+            storage_deposit: 0,
+            storage_usage: 0,
+            sponsored_by_pool: false,
         }
     }
+
+    /// count storage change since prev_storage into this farmer
+    pub fn update_storage_usage(&mut self, prev_storage: StorageUsage) {
+        let storage = env::storage_usage();
+        if storage >= prev_storage {
+            self.storage_usage += storage - prev_storage;
+        } else {
+            self.storage_usage = self.storage_usage.saturating_sub(prev_storage - storage);
+        }
+    }
+
+    /// auto registered with the fee taken from seed, which is not backed by NEAR
+    pub fn is_registered_by_seed(&self) -> bool {
+        self.sponsor_id == env::current_account_id() && !self.sponsored_by_pool
+    }
+
+    /// the part of storage_deposit paid as registration fee
+    pub fn get_registration_deposit(&self) -> Balance {
+        if self.is_registered_by_seed() {
+            0
+        } else {
+            STORAGE_BALANCE_MIN_BOUND
+        }
+    }
+
+    /// the registration fee is always locked, the part of usage above it has to be covered by extra deposit
+    pub fn get_locked_storage_deposit(&self) -> Balance {
+        std::cmp::max(
+            STORAGE_BALANCE_MIN_BOUND,
+            Balance::from(self.storage_usage) * env::storage_byte_cost(),
+        )
+    }
+
+    /// storage deposit plus registration fee paid in seed, which covers storage the same way
+    fn get_storage_coverage(&self) -> Balance {
+        self.storage_deposit + STORAGE_BALANCE_MIN_BOUND - self.get_registration_deposit()
+    }
+
+    pub fn get_available_storage_deposit(&self) -> Balance {
+        self.get_storage_coverage().saturating_sub(self.get_locked_storage_deposit())
+    }
+
+    pub fn is_storage_covered(&self) -> bool {
+        self.get_storage_coverage() >= self.get_locked_storage_deposit()
+    }
+
+    pub fn assert_storage_usage(&self) {
+        require!(self.is_storage_covered(), E102_INSUFFICIENT_STORAGE);
+    }
+
     pub fn add_rewards(&mut self, rewards: &HashMap<AccountId, Balance>) {
         for (reward_token, reward) in rewards {
             self.rewards.insert(
@@ -91,14 +160,81 @@ impl Farmer {
     }
 
     pub fn remove_seed(&mut self, seed_id: &SeedId) {
+        let prev_storage = env::storage_usage();
         if self.seeds.remove(seed_id).is_none() {
             self.vseeds.remove(seed_id);
         }
+        self.update_storage_usage(prev_storage);
     }
 
     pub fn set_seed(&mut self, seed_id: &SeedId, seed: FarmerSeed) {
+        let prev_storage = env::storage_usage();
         self.seeds.remove(seed_id);
         self.vseeds.insert(seed_id, &seed.into());
+        self.update_storage_usage(prev_storage);
+    }
+
+    /// total amount of given booster lent to other farmers
+    pub fn get_delegated_out(&self, booster_id: &SeedId) -> Balance {
+        self.booster_delegations_out
+            .get(booster_id)
+            .map(|delegations| delegations.values().map(|d| d.amount).sum::<Balance>())
+            .unwrap_or(0_u128)
+    }
+
+    /// booster balance used to generate booster ratios,
+    /// own staked power minus the amount lent out, plus the amount lent in
+    pub fn get_booster_balance(&self, booster_id: &SeedId) -> Balance {
+        let own_balance = self
+            .get_seed(booster_id)
+            .map(|v| v.get_basic_seed_power())
+            .unwrap_or(0_u128);
+        own_balance.saturating_sub(self.get_delegated_out(booster_id))
+            + self.booster_delegations_in.get(booster_id).unwrap_or(&0_u128)
+    }
+
+    /// make sure the staked power left can still cover the booster lent out
+    pub fn assert_delegation_covered(&self, seed_id: &SeedId, farmer_seed: &FarmerSeed) {
+        require!(
+            farmer_seed.get_basic_seed_power() >= self.get_delegated_out(seed_id),
+            E210_EXCEED_DELEGATABLE_AMOUNT
+        );
+    }
+
+    pub fn add_delegation_out(&mut self, booster_id: &SeedId, receiver_id: &AccountId, amount: Balance) {
+        let delegation = self.booster_delegations_out
+            .entry(booster_id.clone())
+            .or_default()
+            .entry(receiver_id.clone())
+            .or_insert(BoosterDelegation { amount: 0, delegated_at: 0 });
+        delegation.amount += amount;
+        delegation.delegated_at = env::block_timestamp();
+    }
+
+    pub fn remove_delegation_out(&mut self, booster_id: &SeedId, receiver_id: &AccountId) -> Option<BoosterDelegation> {
+        let delegations = self.booster_delegations_out.get_mut(booster_id)?;
+        let ret = delegations.remove(receiver_id);
+        if delegations.is_empty() {
+            self.booster_delegations_out.remove(booster_id);
+        }
+        ret
+    }
+
+    pub fn add_delegation_in(&mut self, booster_id: &SeedId, amount: Balance) {
+        let prev = self.booster_delegations_in.get(booster_id).copied().unwrap_or(0);
+        self.booster_delegations_in.insert(booster_id.clone(), prev + amount);
+    }
+
+    pub fn sub_delegation_in(&mut self, booster_id: &SeedId, amount: Balance) {
+        if let Some(prev) = self.booster_delegations_in.remove(booster_id) {
+            require!(amount <= prev, E101_INSUFFICIENT_BALANCE);
+            let remain = prev - amount;
+            if remain > 0 {
+                self.booster_delegations_in.insert(booster_id.clone(), remain);
+            }
+        } else {
+            env::panic_str(E211_DELEGATION_NOT_EXIST);
+        }
     }
 }
 
@@ -126,23 +262,42 @@ impl Contract {
                 x_locked_amount: 0,
                 unlock_timestamp: 0,
                 duration_sec: 0,
+                shadow_locked_amount: 0,
+                shadow_x_locked_amount: 0,
+                shadow_unlock_timestamp: 0,
+                shadow_duration_sec: 0,
+                shadow_by_provider: HashMap::new(),
+                nft_ids: HashMap::new(),
                 boost_ratios: self.gen_booster_ratios(&seed.seed_id, farmer),
                 user_rps: HashMap::new(),
             });
 
         let farmer_seed_power = farmer_seed.get_seed_power();
 
-        let mut new_user_rps = HashMap::new();
-        for (farm_id, vfarm) in &seed.farms {
-            let (seed_farm_rps, seed_farm_terms_reward_token, seed_farm_total_reward) = match vfarm {
+        let mut farm_states: Vec<(FarmId, BigDecimal, AccountId, Balance)> = seed
+            .farms
+            .iter()
+            .map(|(farm_id, vfarm)| match vfarm {
                 VSeedFarm::V0(farm) => {
-                    (farm.rps, farm.terms.reward_token.clone(), farm.total_reward)
+                    (farm_id.clone(), farm.rps, farm.terms.reward_token.clone(), farm.total_reward)
+                }
+                VSeedFarm::V1(farm) => {
+                    (farm_id.clone(), farm.rps, farm.terms.reward_token.clone(), farm.total_reward)
                 }
                 VSeedFarm::Current(farm) => {
-                    (farm.rps, farm.terms.reward_token.clone(), farm.total_reward)
+                    (farm_id.clone(), farm.rps, farm.terms.reward_token.clone(), farm.total_reward)
                 }
-            };
-            let farmer_rps = farmer_seed.user_rps.get(farm_id).unwrap_or(&BigDecimal::zero()).clone();
+            })
+            .collect();
+        // outdated farms keep their final rps during the claim grace period
+        for farm_id in seed.claimable_outdated_farms.keys() {
+            let farm = self.internal_unwrap_outdated_farm(farm_id);
+            farm_states.push((farm_id.clone(), farm.rps, farm.terms.reward_token, farm.total_reward));
+        }
+
+        let mut new_user_rps = HashMap::new();
+        for (farm_id, seed_farm_rps, seed_farm_terms_reward_token, seed_farm_total_reward) in farm_states {
+            let farmer_rps = farmer_seed.user_rps.get(&farm_id).unwrap_or(&BigDecimal::zero()).clone();
             let diff = seed_farm_rps - farmer_rps;
             let reward_amount = diff.round_down_mul_u128(farmer_seed_power);
             if reward_amount > 0 {
@@ -177,7 +332,11 @@ impl Contract {
         seed.total_seed_power = seed.total_seed_power + farmer_seed.get_seed_power() - prev;
 
         farmer.set_seed(&seed.seed_id, farmer_seed);
+        let (claimed, outdated_claimed): (HashMap<FarmId, Balance>, HashMap<FarmId, Balance>) = claimed
+            .into_iter()
+            .partition(|(farm_id, _)| seed.farms.contains_key(farm_id));
         seed.update_claimed(&claimed);
+        self.internal_update_outdated_claimed(&outdated_claimed);
         self.internal_set_seed(&seed_id, seed);
     }
 
@@ -190,7 +349,94 @@ impl Contract {
             .expect(E100_ACC_NOT_REGISTERED)
     }
 
+    /// count storage change of farmer's entries kept outside Farmer since prev_storage into the farmer,
+    /// if it is still registered. Storage is not checked, as failed transfers are recorded by callbacks.
+    pub fn internal_update_farmer_storage_usage(&mut self, farmer_id: &AccountId, prev_storage: StorageUsage) {
+        if let Some(mut farmer) = self.internal_get_farmer(farmer_id) {
+            farmer.update_storage_usage(prev_storage);
+            self.internal_save_farmer(farmer_id, farmer);
+        }
+    }
+
+    /// save farmer and charge its storage growth, panic if storage deposit can not cover it
     pub fn internal_set_farmer(&mut self, farmer_id: &AccountId, farmer: Farmer) {
-        self.data_mut().farmers.insert(farmer_id, &farmer.into());
+        self.internal_save_farmer(farmer_id, farmer).assert_storage_usage();
+    }
+
+    /// save farmer and charge its storage growth without checking storage deposit.
+    /// Only for paths which must not fail on farmer's storage: callbacks, shadow providers and the owner,
+    /// or when the caller checks storage on its own.
+    pub fn internal_save_farmer(&mut self, farmer_id: &AccountId, farmer: Farmer) -> Farmer {
+        let prev_storage = env::storage_usage();
+        let v_farmer: VFarmer = farmer.into();
+        self.data_mut().farmers.insert(farmer_id, &v_farmer);
+        let mut farmer: Farmer = v_farmer.into();
+        let storage_changed = env::storage_usage() != prev_storage;
+        farmer.update_storage_usage(prev_storage);
+        if storage_changed {
+            // storage_usage is fixed size, saving again won't change the storage usage
+            let v_farmer: VFarmer = farmer.into();
+            self.data_mut().farmers.insert(farmer_id, &v_farmer);
+            farmer = v_farmer.into();
+        }
+        farmer
+    }
+
+    /// set the farmer seed, or remove it if empty, and keep seed farmer index and seed statistics in sync.
+    /// Only farmers in the index are counted in seed statistics.
+    pub fn internal_set_farmer_seed(&mut self, farmer: &mut Farmer, seed: &mut Seed, farmer_seed: FarmerSeed) {
+        let seed_id = seed.seed_id.clone();
+        if self.internal_is_seed_farmer(&farmer.farmer_id, &seed_id) {
+            seed.sub_farmer_stats(&farmer.get_seed_unwrap(&seed_id));
+        }
+        let is_staking = !farmer_seed.is_empty();
+        if is_staking {
+            seed.add_farmer_stats(&farmer_seed);
+            farmer.set_seed(&seed_id, farmer_seed);
+        } else {
+            farmer.remove_seed(&seed_id);
+        }
+        let prev_storage = env::storage_usage();
+        self.internal_update_seed_farmer(&farmer.farmer_id, &seed_id, is_staking);
+        farmer.update_storage_usage(prev_storage);
+    }
+
+    pub fn internal_is_seed_farmer(&self, farmer_id: &AccountId, seed_id: &SeedId) -> bool {
+        self.data().seed_farmers.get(seed_id).map(|v| v.contains(farmer_id)).unwrap_or(false)
+    }
+
+    pub fn internal_update_sponsored_farmer(&mut self, sponsor_id: &AccountId, farmer_id: &AccountId, is_sponsored: bool) {
+        if sponsor_id == farmer_id {
+            return;
+        }
+        let mut sponsored_farmers = self.data().sponsored_farmers.get(sponsor_id).unwrap_or_else(|| {
+            UnorderedSet::new(StorageKeys::SponsoredFarmer {
+                sponsor_id: sponsor_id.clone(),
+            })
+        });
+        let changed = if is_sponsored {
+            sponsored_farmers.insert(farmer_id)
+        } else {
+            sponsored_farmers.remove(farmer_id)
+        };
+        if changed {
+            self.data_mut().sponsored_farmers.insert(sponsor_id, &sponsored_farmers);
+        }
+    }
+
+    pub fn internal_update_seed_farmer(&mut self, farmer_id: &AccountId, seed_id: &SeedId, is_staking: bool) {
+        let mut seed_farmers = self.data().seed_farmers.get(seed_id).unwrap_or_else(|| {
+            UnorderedSet::new(StorageKeys::SeedFarmer {
+                seed_id: seed_id.clone(),
+            })
+        });
+        let changed = if is_staking {
+            seed_farmers.insert(farmer_id)
+        } else {
+            seed_farmers.remove(farmer_id)
+        };
+        if changed {
+            self.data_mut().seed_farmers.insert(seed_id, &seed_farmers);
+        }
     }
 }
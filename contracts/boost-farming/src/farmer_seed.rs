@@ -19,6 +19,21 @@ pub struct FarmerSeed {
     pub unlock_timestamp: u64,
     /// The duration of current locking in seconds.
     pub duration_sec: u32,
+    /// The amount of locked shadow seed, the real token is kept by shadow provider.
+    #[serde(with = "u128_dec_format")]
+    pub shadow_locked_amount: Balance,
+    /// The amount of power for those locked shadow amount.
+    #[serde(with = "u128_dec_format")]
+    pub shadow_x_locked_amount: Balance,
+    /// When the locked shadow can be removed in nanoseconds.
+    #[serde(with = "u64_dec_format")]
+    pub shadow_unlock_timestamp: u64,
+    /// The duration of current shadow locking in seconds.
+    pub shadow_duration_sec: u32,
+    /// <provider_id, shadow amount> cast by each shadow provider, including locked ones.
+    pub shadow_by_provider: HashMap<AccountId, U128>,
+    /// <nft token_id, seed amount valued at deposit> of staked nfts, they are counted in free_amount.
+    pub nft_ids: HashMap<String, U128>,
     /// <booster_id, booster-ratio>
     pub boost_ratios: HashMap<SeedId, f64>,
     #[serde(skip)]
@@ -27,12 +42,14 @@ pub struct FarmerSeed {
 
 #[derive(BorshSerialize, BorshDeserialize)]
 pub enum VFarmerSeed {
+    V0(FarmerSeedV0),
     Current(FarmerSeed),
 }
 
 impl From<VFarmerSeed> for FarmerSeed {
     fn from(v: VFarmerSeed) -> Self {
         match v {
+            VFarmerSeed::V0(c) => c.into(),
             VFarmerSeed::Current(c) => c,
         }
     }
@@ -52,11 +69,16 @@ impl FarmerSeed {
     }
 
     pub fn get_basic_seed_power(&self) -> Balance {
-        self.free_amount + self.shadow_amount + self.x_locked_amount
+        self.free_amount + self.shadow_amount + self.x_locked_amount + self.shadow_x_locked_amount
+    }
+
+    /// total seed amount regardless of power
+    pub fn get_seed_amount(&self) -> Balance {
+        self.free_amount + self.shadow_amount + self.locked_amount + self.shadow_locked_amount
     }
 
     pub fn is_empty(&self) -> bool {
-        self.free_amount + self.shadow_amount + self.locked_amount == 0
+        self.get_seed_amount() == 0
     }
 
     pub fn add_free(&mut self, amount: Balance) -> Balance {
@@ -72,19 +94,133 @@ impl FarmerSeed {
         prev - self.get_seed_power()
     }
 
+    /// total shadow amount including locked ones
+    pub fn get_shadow_amount(&self) -> Balance {
+        self.shadow_amount + self.shadow_locked_amount
+    }
+
+    /// shadow amount cast by the provider, shadow not attributed to any provider
+    /// was cast by ref-exchange before providers were tracked, so it counts for ref-exchange.
+    pub fn get_provider_shadow(&self, provider_id: &AccountId, is_ref_exchange: bool) -> Balance {
+        let cast = self.shadow_by_provider.get(provider_id).map(|v| v.0).unwrap_or(0);
+        if is_ref_exchange {
+            let attributed: Balance = self.shadow_by_provider.values().map(|v| v.0).sum();
+            cast + self.get_shadow_amount() - attributed
+        } else {
+            cast
+        }
+    }
+
+    pub fn add_provider_shadow(&mut self, provider_id: &AccountId, amount: Balance) {
+        self.shadow_by_provider.entry(provider_id.clone()).or_insert(U128(0)).0 += amount;
+    }
+
+    /// only the provider who cast the shadow can remove it, call before shadow amount changes
+    pub fn remove_provider_shadow(&mut self, provider_id: &AccountId, amount: Balance, is_ref_exchange: bool) {
+        require!(amount <= self.get_provider_shadow(provider_id, is_ref_exchange), E704_SHADOW_NOT_CAST_BY_PROVIDER);
+        // recorded part first, the rest is unattributed shadow of ref-exchange
+        if let Some(cast) = self.shadow_by_provider.get_mut(provider_id) {
+            cast.0 -= std::cmp::min(amount, cast.0);
+            if cast.0 == 0 {
+                self.shadow_by_provider.remove(provider_id);
+            }
+        }
+    }
+
+    /// overwrite shadow cast by the provider with the given amount, shadow of other providers is kept,
+    /// return the provider's previous shadow amount
+    pub fn reset_provider_shadow(&mut self, provider_id: &AccountId, amount: Balance, is_ref_exchange: bool) -> Balance {
+        let prev_amount = self.get_provider_shadow(provider_id, is_ref_exchange);
+        self.reset_shadow(self.get_shadow_amount() - prev_amount + amount);
+        if amount > 0 {
+            self.shadow_by_provider.insert(provider_id.clone(), U128(amount));
+        } else {
+            self.shadow_by_provider.remove(provider_id);
+        }
+        prev_amount
+    }
+
+    /// force total shadow to the given amount, shrink locked shadow only if free shadow is not enough.
+    pub fn reset_shadow(&mut self, amount: Balance) {
+        if amount >= self.shadow_locked_amount {
+            self.shadow_amount = amount - self.shadow_locked_amount;
+        } else if amount > 0 {
+            self.shadow_amount = 0;
+            self.shadow_x_locked_amount = u128_ratio(self.shadow_x_locked_amount, amount, self.shadow_locked_amount);
+            self.shadow_locked_amount = amount;
+        } else {
+            self.shadow_amount = 0;
+            self.shadow_locked_amount = 0;
+            self.shadow_x_locked_amount = 0;
+            self.shadow_unlock_timestamp = 0;
+            self.shadow_duration_sec = 0;
+        }
+    }
+
+    pub fn add_nft(&mut self, token_id: &str, amount: Balance) -> Balance {
+        require!(self.nft_ids.insert(token_id.to_string(), U128(amount)).is_none(), E604_NFT_ALREADY_STAKED);
+        self.add_free(amount)
+    }
+
+    /// return the nft's seed amount and decreased power
+    pub fn withdraw_nft(&mut self, token_id: &str) -> (Balance, Balance) {
+        let amount = self.nft_ids.remove(token_id).expect(E605_NFT_NOT_STAKED).0;
+        (amount, self.withdraw_free(amount))
+    }
+
     pub fn add_shadow(&mut self, amount: Balance) -> Balance {
         let prev = self.get_seed_power();
         self.shadow_amount += amount;
         self.get_seed_power() - prev
     }
 
+    /// remove from free shadow first, the rest comes from shadow lock which must be expired.
     pub fn withdraw_shadow(&mut self, amount: Balance) -> Balance {
-        require!(amount <= self.shadow_amount, E101_INSUFFICIENT_BALANCE);
+        require!(amount <= self.shadow_amount + self.shadow_locked_amount, E101_INSUFFICIENT_BALANCE);
         let prev = self.get_seed_power();
-        self.shadow_amount -= amount;
+        let free_part = std::cmp::min(amount, self.shadow_amount);
+        self.shadow_amount -= free_part;
+
+        let locked_part = amount - free_part;
+        if locked_part > 0 {
+            require!(self.shadow_unlock_timestamp <= env::block_timestamp(), E305_STILL_IN_LOCK);
+            if locked_part < self.shadow_locked_amount {
+                let new_x = u128_ratio(self.shadow_x_locked_amount, self.shadow_locked_amount - locked_part, self.shadow_locked_amount);
+                require!(new_x < self.shadow_x_locked_amount, E306_LOCK_AMOUNT_TOO_SMALL);
+                self.shadow_x_locked_amount = new_x;
+            } else {
+                self.shadow_x_locked_amount = 0;
+                self.shadow_unlock_timestamp = 0;
+                self.shadow_duration_sec = 0;
+            }
+            self.shadow_locked_amount -= locked_part;
+        }
         prev - self.get_seed_power()
     }
 
+    pub fn add_shadow_lock(&mut self, amount: Balance, duration_sec: u32, config: &Config) -> Balance {
+        let prev = self.get_seed_power();
+
+        let timestamp = env::block_timestamp();
+        let new_unlock_timestamp = timestamp + to_nano(duration_sec);
+
+        if self.shadow_unlock_timestamp > 0 && self.shadow_unlock_timestamp > timestamp {
+            // exist x locked need relock
+            require!(self.shadow_unlock_timestamp <= new_unlock_timestamp, E304_CAUSE_PRE_UNLOCK);
+            let relocked_x = compute_x_amount(config, self.shadow_locked_amount, duration_sec);
+            self.shadow_x_locked_amount = std::cmp::max(self.shadow_x_locked_amount, relocked_x);
+            let extra_x = compute_x_amount(config, amount, duration_sec);
+            self.shadow_x_locked_amount += extra_x;
+        } else {
+            self.shadow_x_locked_amount = compute_x_amount(config, self.shadow_locked_amount + amount, duration_sec);
+        }
+        self.shadow_unlock_timestamp = new_unlock_timestamp;
+        self.shadow_locked_amount += amount;
+        self.shadow_duration_sec = duration_sec;
+
+        self.get_seed_power() - prev
+    }
+
     pub fn add_lock(&mut self, amount: Balance, duration_sec: u32, config: &Config) -> Balance {
         let prev = self.get_seed_power();
 
@@ -206,6 +342,25 @@ impl FarmerSeed {
         } else {
             self.x_locked_amount = 0;
         }
+
+        if self.shadow_unlock_timestamp > timestamp {
+            let maximum_locking_duration_sec = config.maximum_locking_duration_sec;
+            let remain_duration_sec = nano_to_sec(self.shadow_unlock_timestamp - timestamp);
+
+            let max_x_locked_amount = compute_x_amount(config, self.shadow_locked_amount, maximum_locking_duration_sec);
+            let recalc_x_locked_amount = compute_x_amount(config, self.shadow_locked_amount, std::cmp::min(self.shadow_duration_sec, maximum_locking_duration_sec));
+            self.shadow_x_locked_amount = std::cmp::min(
+                max_x_locked_amount,
+                std::cmp::max(self.shadow_x_locked_amount, recalc_x_locked_amount)
+            );
+
+            if remain_duration_sec > maximum_locking_duration_sec {
+                self.shadow_unlock_timestamp = timestamp + to_nano(maximum_locking_duration_sec);
+                self.shadow_duration_sec = maximum_locking_duration_sec;
+            }
+        } else {
+            self.shadow_x_locked_amount = 0;
+        }
     }
 }
 
@@ -4,7 +4,7 @@ use crate::*;
 pub struct ContractDataV0100 {
     pub owner_id: AccountId,
     pub operators: UnorderedSet<AccountId>,
-    pub config: LazyOption<Config>,
+    pub config: LazyOption<ConfigV0>,
     pub seeds: UnorderedMap<SeedId, VSeed>,
     pub farmers: LookupMap<AccountId, VFarmer>,
     pub outdated_farms: UnorderedMap<FarmId, VSeedFarm>,
@@ -38,12 +38,25 @@ impl From<ContractDataV0100> for ContractData {
             next_owner_accept_deadline: None,
             ref_exchange_id: owner_id,
             operators,
-            config,
+            config: upgrade_config(config),
             seeds,
             farmers,
             outdated_farms,
             seeds_slashed,
+            seeds_register_fee: UnorderedMap::new(StorageKeys::SeedRegisterFee),
             seeds_lostfound,
+            farmer_seeds_lostfound: LookupMap::new(StorageKeys::FarmerSeedLostfound),
+            rewards_lostfound: UnorderedMap::new(StorageKeys::RewardLostfound),
+            nfts_lostfound: UnorderedMap::new(StorageKeys::NftLostfound),
+            shadow_providers: UnorderedMap::new(StorageKeys::ShadowProvider),
+            shadow_stakers: UnorderedSet::new(StorageKeys::ShadowStaker),
+            farmer_ids: UnorderedSet::new(StorageKeys::FarmerIndex),
+            seed_farmers: LookupMap::new(StorageKeys::SeedFarmers),
+            sponsored_farmers: LookupMap::new(StorageKeys::SponsoredFarmers),
+            farm_checkpoints: LookupMap::new(StorageKeys::FarmCheckpoint),
+            farm_refunds: LookupMap::new(StorageKeys::FarmRefund),
+            reward_tokens: UnorderedMap::new(StorageKeys::RewardToken),
+            farmer_sponsor_pool: 0,
             farmer_count,
             farm_count,
             state: RunningState::Running,
@@ -76,6 +89,11 @@ impl From<FarmerV0> for Farmer {
             vseeds: UnorderedMap::new(StorageKeys::VFarmerSeed {
                 account_id: farmer_id.clone(),
             }),
+            booster_delegations_out: HashMap::new(),
+            booster_delegations_in: HashMap::new(),
+            storage_deposit: STORAGE_BALANCE_MIN_BOUND,
+            storage_usage: 0,
+            sponsored_by_pool: false,
         }
     }
 }
@@ -85,7 +103,7 @@ pub struct ContractDataV0101 {
     pub owner_id: AccountId,
     pub state: RunningState,
     pub operators: UnorderedSet<AccountId>,
-    pub config: LazyOption<Config>,
+    pub config: LazyOption<ConfigV0>,
     pub seeds: UnorderedMap<SeedId, VSeed>,
     pub farmers: LookupMap<AccountId, VFarmer>,
     pub outdated_farms: UnorderedMap<FarmId, VSeedFarm>,
@@ -121,12 +139,25 @@ impl From<ContractDataV0101> for ContractData {
             ref_exchange_id: owner_id,
             state,
             operators,
-            config,
+            config: upgrade_config(config),
             seeds,
             farmers,
             outdated_farms,
             seeds_slashed,
+            seeds_register_fee: UnorderedMap::new(StorageKeys::SeedRegisterFee),
             seeds_lostfound,
+            farmer_seeds_lostfound: LookupMap::new(StorageKeys::FarmerSeedLostfound),
+            rewards_lostfound: UnorderedMap::new(StorageKeys::RewardLostfound),
+            nfts_lostfound: UnorderedMap::new(StorageKeys::NftLostfound),
+            shadow_providers: UnorderedMap::new(StorageKeys::ShadowProvider),
+            shadow_stakers: UnorderedSet::new(StorageKeys::ShadowStaker),
+            farmer_ids: UnorderedSet::new(StorageKeys::FarmerIndex),
+            seed_farmers: LookupMap::new(StorageKeys::SeedFarmers),
+            sponsored_farmers: LookupMap::new(StorageKeys::SponsoredFarmers),
+            farm_checkpoints: LookupMap::new(StorageKeys::FarmCheckpoint),
+            farm_refunds: LookupMap::new(StorageKeys::FarmRefund),
+            reward_tokens: UnorderedMap::new(StorageKeys::RewardToken),
+            farmer_sponsor_pool: 0,
             farmer_count,
             farm_count,
             
@@ -134,13 +165,39 @@ impl From<ContractDataV0101> for ContractData {
     }
 }
 
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Clone)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug, Deserialize))]
+#[serde(crate = "near_sdk::serde")]
+pub struct FarmTermsV0 {
+    pub reward_token: AccountId,
+    pub start_at: u32,
+    #[serde(with = "u128_dec_format")]
+    pub daily_reward: Balance,
+}
+
+impl From<FarmTermsV0> for FarmTerms {
+    fn from(a: FarmTermsV0) -> Self {
+        let FarmTermsV0 {
+            reward_token,
+            start_at,
+            daily_reward,
+        } = a;
+        Self {
+            reward_token,
+            start_at,
+            daily_reward,
+            options: FarmOptions::default(),
+        }
+    }
+}
+
 #[derive(BorshSerialize, BorshDeserialize, Serialize, Clone)]
 #[cfg_attr(not(target_arch = "wasm32"), derive(Debug, Deserialize))]
 #[serde(crate = "near_sdk::serde")]
 pub struct SeedFarmV0 {
     pub farm_id: FarmId,
 
-    pub terms: FarmTerms,
+    pub terms: FarmTermsV0,
 
     /// total reward send into this farm by far,
     /// every time reward deposited in, add to this field
@@ -260,7 +317,7 @@ impl From<SeedFarmV0> for SeedFarm {
         } = a;
         Self {
             farm_id,
-            terms,
+            terms: terms.into(),
             total_reward,
             distributed_at,
             distributed_reward,
@@ -268,7 +325,75 @@ impl From<SeedFarmV0> for SeedFarm {
             amount_of_beneficiary,
             amount_of_withdrew_beneficiary: 0,
             rps,
+            ended_at: 0,
+            claimable_until: 0,
+            status,
+            reward_token_metadata: None,
+        }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Clone)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug, Deserialize))]
+#[serde(crate = "near_sdk::serde")]
+pub struct SeedFarmV1 {
+    pub farm_id: FarmId,
+
+    pub terms: FarmTermsV0,
+
+    #[serde(with = "u128_dec_format")]
+    pub total_reward: Balance,
+
+    #[serde(with = "u64_dec_format")]
+    pub distributed_at: Timestamp,
+
+    #[serde(with = "u128_dec_format")]
+    pub distributed_reward: Balance,
+
+    #[serde(with = "u128_dec_format")]
+    pub claimed_reward: Balance,
+
+    #[serde(with = "u128_dec_format")]
+    pub amount_of_beneficiary: Balance,
+
+    #[serde(with = "u128_dec_format")]
+    pub amount_of_withdrew_beneficiary: Balance,
+
+    #[serde(skip)]
+    pub rps: BigDecimal,
+
+    #[borsh_skip]
+    pub status: Option<FarmStatus>,
+}
+
+impl From<SeedFarmV1> for SeedFarm {
+    fn from(a: SeedFarmV1) -> Self {
+        let SeedFarmV1 {
+            farm_id,
+            terms,
+            total_reward,
+            distributed_at,
+            distributed_reward,
+            claimed_reward,
+            amount_of_beneficiary,
+            amount_of_withdrew_beneficiary,
+            rps,
             status
+        } = a;
+        Self {
+            farm_id,
+            terms: terms.into(),
+            total_reward,
+            distributed_at,
+            distributed_reward,
+            claimed_reward,
+            amount_of_beneficiary,
+            amount_of_withdrew_beneficiary,
+            rps,
+            ended_at: 0,
+            claimable_until: 0,
+            status,
+            reward_token_metadata: None,
         }
     }
 }
@@ -280,7 +405,7 @@ pub struct ContractDataV0102 {
     pub next_owner_accept_deadline: Option<u64>,
     pub state: RunningState,
     pub operators: UnorderedSet<AccountId>,
-    pub config: LazyOption<Config>,
+    pub config: LazyOption<ConfigV0>,
     pub seeds: UnorderedMap<SeedId, VSeed>,
     pub farmers: LookupMap<AccountId, VFarmer>,
     pub outdated_farms: UnorderedMap<FarmId, VSeedFarm>,
@@ -318,12 +443,25 @@ impl From<ContractDataV0102> for ContractData {
             ref_exchange_id: owner_id,
             state,
             operators,
-            config,
+            config: upgrade_config(config),
             seeds,
             farmers,
             outdated_farms,
             seeds_slashed,
+            seeds_register_fee: UnorderedMap::new(StorageKeys::SeedRegisterFee),
             seeds_lostfound,
+            farmer_seeds_lostfound: LookupMap::new(StorageKeys::FarmerSeedLostfound),
+            rewards_lostfound: UnorderedMap::new(StorageKeys::RewardLostfound),
+            nfts_lostfound: UnorderedMap::new(StorageKeys::NftLostfound),
+            shadow_providers: UnorderedMap::new(StorageKeys::ShadowProvider),
+            shadow_stakers: UnorderedSet::new(StorageKeys::ShadowStaker),
+            farmer_ids: UnorderedSet::new(StorageKeys::FarmerIndex),
+            seed_farmers: LookupMap::new(StorageKeys::SeedFarmers),
+            sponsored_farmers: LookupMap::new(StorageKeys::SponsoredFarmers),
+            farm_checkpoints: LookupMap::new(StorageKeys::FarmCheckpoint),
+            farm_refunds: LookupMap::new(StorageKeys::FarmRefund),
+            reward_tokens: UnorderedMap::new(StorageKeys::RewardToken),
+            farmer_sponsor_pool: 0,
             farmer_count,
             farm_count,
             
@@ -365,6 +503,12 @@ impl From<FarmerSeedOld> for FarmerSeed {
             x_locked_amount,
             unlock_timestamp,
             duration_sec,
+            shadow_locked_amount: 0,
+            shadow_x_locked_amount: 0,
+            shadow_unlock_timestamp: 0,
+            shadow_duration_sec: 0,
+            shadow_by_provider: HashMap::new(),
+            nft_ids: HashMap::new(),
             boost_ratios,
             user_rps
         }
@@ -399,6 +543,260 @@ impl From<FarmerV1> for Farmer {
             vseeds: UnorderedMap::new(StorageKeys::VFarmerSeed {
                 account_id: farmer_id.clone(),
             }),
+            booster_delegations_out: HashMap::new(),
+            booster_delegations_in: HashMap::new(),
+            storage_deposit: STORAGE_BALANCE_MIN_BOUND,
+            storage_usage: 0,
+            sponsored_by_pool: false,
+        }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct ConfigV0 {
+    pub seed_slash_rate: u32,
+    pub booster_seeds: HashMap<SeedId, BoosterInfo>,
+    pub max_num_farms_per_booster: u32,
+    pub max_num_farms_per_seed: u32,
+    pub maximum_locking_duration_sec: DurationSec,
+    pub max_locking_multiplier: u32,
+}
+
+impl From<ConfigV0> for Config {
+    fn from(a: ConfigV0) -> Self {
+        let ConfigV0 {
+            seed_slash_rate,
+            booster_seeds,
+            max_num_farms_per_booster,
+            max_num_farms_per_seed,
+            maximum_locking_duration_sec,
+            max_locking_multiplier,
+        } = a;
+        Self {
+            seed_slash_rate,
+            booster_seeds,
+            max_num_farms_per_booster,
+            max_num_farms_per_seed,
+            maximum_locking_duration_sec,
+            max_locking_multiplier,
+            booster_delegation_cooldown_sec: DEFAULT_BOOSTER_DELEGATION_COOLDOWN_SEC,
+            farm_checkpoint_interval_sec: 0,
+            max_num_farm_checkpoints: DEFAULT_MAX_NUM_FARM_CHECKPOINTS,
+            farm_cleanup_grace_sec: DEFAULT_FARM_CLEANUP_GRACE_SEC,
+            outdated_farm_claim_grace_sec: DEFAULT_OUTDATED_FARM_CLAIM_GRACE_SEC,
+            auto_register_farmer: false,
+            wnear_id: None,
+        }
+    }
+}
+
+/// rewrite the stored config in current layout, the storage key stays the same
+fn upgrade_config(config: LazyOption<ConfigV0>) -> LazyOption<Config> {
+    let config: Config = config.get().expect(E003_NOT_INIT).into();
+    LazyOption::new(StorageKeys::Config, Some(&config))
+}
+
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct ContractDataV0103 {
+    pub owner_id: AccountId,
+    pub next_owner_id: Option<AccountId>,
+    pub next_owner_accept_deadline: Option<u64>,
+    pub ref_exchange_id: AccountId,
+    pub state: RunningState,
+    pub operators: UnorderedSet<AccountId>,
+    pub config: LazyOption<ConfigV0>,
+    pub seeds: UnorderedMap<SeedId, VSeed>,
+    pub farmers: LookupMap<AccountId, VFarmer>,
+    pub outdated_farms: UnorderedMap<FarmId, VSeedFarm>,
+    // all slashed seed would recorded in here
+    pub seeds_slashed: UnorderedMap<SeedId, Balance>,
+    // if unstake seed encounter error, the seed would go to here
+    pub seeds_lostfound: UnorderedMap<SeedId, Balance>,
+
+    // for statistic
+    farmer_count: u64,
+    farm_count: u64,
+}
+
+impl From<ContractDataV0103> for ContractData {
+    fn from(a: ContractDataV0103) -> Self {
+        let ContractDataV0103 {
+            owner_id,
+            next_owner_id,
+            next_owner_accept_deadline,
+            ref_exchange_id,
+            state,
+            operators,
+            config,
+            seeds,
+            farmers,
+            outdated_farms,
+            seeds_slashed,
+            seeds_lostfound,
+            farmer_count,
+            farm_count,
+        } = a;
+        Self {
+            owner_id,
+            next_owner_id,
+            next_owner_accept_deadline,
+            ref_exchange_id,
+            state,
+            operators,
+            config: upgrade_config(config),
+            seeds,
+            farmers,
+            outdated_farms,
+            seeds_slashed,
+            seeds_register_fee: UnorderedMap::new(StorageKeys::SeedRegisterFee),
+            seeds_lostfound,
+            farmer_seeds_lostfound: LookupMap::new(StorageKeys::FarmerSeedLostfound),
+            rewards_lostfound: UnorderedMap::new(StorageKeys::RewardLostfound),
+            nfts_lostfound: UnorderedMap::new(StorageKeys::NftLostfound),
+            shadow_providers: UnorderedMap::new(StorageKeys::ShadowProvider),
+            shadow_stakers: UnorderedSet::new(StorageKeys::ShadowStaker),
+            farmer_ids: UnorderedSet::new(StorageKeys::FarmerIndex),
+            seed_farmers: LookupMap::new(StorageKeys::SeedFarmers),
+            sponsored_farmers: LookupMap::new(StorageKeys::SponsoredFarmers),
+            farm_checkpoints: LookupMap::new(StorageKeys::FarmCheckpoint),
+            farm_refunds: LookupMap::new(StorageKeys::FarmRefund),
+            reward_tokens: UnorderedMap::new(StorageKeys::RewardToken),
+            farmer_sponsor_pool: 0,
+            farmer_count,
+            farm_count,
         }
     }
-}
\ No newline at end of file
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct FarmerV2 {
+    /// A copy of an farmer ID. Saves one storage_read when iterating on farmers.
+    pub farmer_id: AccountId,
+    pub sponsor_id: AccountId,
+    /// Amounts of various reward tokens the farmer claimed.
+    pub rewards: HashMap<AccountId, Balance>,
+    /// Various seed tokens the farmer staked.
+    pub seeds: UnorderedMap<SeedId, FarmerSeedOld>,
+    pub vseeds: UnorderedMap<SeedId, VFarmerSeed>,
+}
+
+impl From<FarmerV2> for Farmer {
+    fn from(a: FarmerV2) -> Self {
+        let FarmerV2 {
+            farmer_id,
+            sponsor_id,
+            rewards,
+            seeds,
+            vseeds,
+        } = a;
+
+        Self {
+            farmer_id,
+            sponsor_id,
+            rewards,
+            seeds,
+            vseeds,
+            booster_delegations_out: HashMap::new(),
+            booster_delegations_in: HashMap::new(),
+            storage_deposit: STORAGE_BALANCE_MIN_BOUND,
+            storage_usage: 0,
+            sponsored_by_pool: false,
+        }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct FarmerSeedV0 {
+    pub free_amount: Balance,
+    pub shadow_amount: Balance,
+    /// The amount of locked token.
+    pub locked_amount: Balance,
+    /// The amount of power for those locked amount.
+    pub x_locked_amount: Balance,
+    /// When the locking token can be unlocked without slash in nanoseconds.
+    pub unlock_timestamp: u64,
+    /// The duration of current locking in seconds.
+    pub duration_sec: u32,
+    /// <booster_id, booster-ratio>
+    pub boost_ratios: HashMap<SeedId, f64>,
+    pub user_rps: HashMap<FarmId, BigDecimal>,
+}
+
+impl From<FarmerSeedV0> for FarmerSeed {
+    fn from(a: FarmerSeedV0) -> Self {
+        let FarmerSeedV0 {
+            free_amount,
+            shadow_amount,
+            locked_amount,
+            x_locked_amount,
+            unlock_timestamp,
+            duration_sec,
+            boost_ratios,
+            user_rps
+        } = a;
+        Self {
+            free_amount,
+            shadow_amount,
+            locked_amount,
+            x_locked_amount,
+            unlock_timestamp,
+            duration_sec,
+            shadow_locked_amount: 0,
+            shadow_x_locked_amount: 0,
+            shadow_unlock_timestamp: 0,
+            shadow_duration_sec: 0,
+            shadow_by_provider: HashMap::new(),
+            nft_ids: HashMap::new(),
+            boost_ratios,
+            user_rps
+        }
+    }
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct SeedV0 {
+    pub seed_id: SeedId,
+    pub seed_decimal: u32,
+    pub farms: HashMap<FarmId, VSeedFarm>,
+    pub next_index: u32,
+    pub total_seed_amount: Balance,
+    pub total_seed_power: Balance,
+    pub min_deposit: Balance,
+    pub slash_rate: u32,
+    pub min_locking_duration_sec: DurationSec,
+}
+
+impl From<SeedV0> for Seed {
+    fn from(a: SeedV0) -> Self {
+        let SeedV0 {
+            seed_id,
+            seed_decimal,
+            farms,
+            next_index,
+            total_seed_amount,
+            total_seed_power,
+            min_deposit,
+            slash_rate,
+            min_locking_duration_sec,
+        } = a;
+        Self {
+            seed_id,
+            seed_decimal,
+            farms,
+            next_index,
+            total_seed_amount,
+            total_seed_power,
+            min_deposit,
+            slash_rate,
+            min_locking_duration_sec,
+            farmer_count: 0,
+            total_free_amount: 0,
+            total_locked_amount: 0,
+            total_x_locked_amount: 0,
+            total_shadow_amount: 0,
+            claimable_outdated_farms: HashMap::new(),
+            register_fee: 0,
+            nft_valuation: None,
+        }
+    }
+}
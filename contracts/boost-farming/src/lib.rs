@@ -8,16 +8,22 @@ mod actions_of_farmer_seed;
 mod actions_of_seed;
 mod big_decimal;
 mod booster;
+mod booster_delegation;
 mod errors;
 mod events;
 mod farmer;
+mod farm_checkpoint;
+mod farm_refund;
 mod farmer_seed;
 mod legacy;
 mod management;
+mod nft_actions;
 mod owner;
+mod reward_token;
 mod seed;
 mod seed_farm;
 mod shadow_actions;
+mod solvency;
 mod storage_impl;
 mod token_receiver;
 mod utils;
@@ -25,12 +31,17 @@ mod view;
 
 pub use crate::big_decimal::*;
 pub use crate::booster::*;
+pub use crate::booster_delegation::*;
 pub use crate::errors::*;
 pub use crate::events::*;
 pub use crate::farmer::*;
+pub use crate::farm_checkpoint::*;
+pub use crate::farm_refund::*;
 pub use crate::farmer_seed::*;
 pub use crate::legacy::*;
+pub use crate::nft_actions::*;
 pub use crate::owner::{ImportFarmerInfo, ImportSeedInfo};
+pub use crate::reward_token::*;
 pub use crate::seed::*;
 pub use crate::seed_farm::*;
 pub use crate::shadow_actions::*;
@@ -43,7 +54,7 @@ use near_sdk::serde::{Deserialize, Serialize};
 use near_sdk::BorshStorageKey;
 use near_sdk::{
     assert_one_yocto, env, near_bindgen, require, AccountId, Balance, PanicOnDefault, Promise,
-    PromiseResult, Timestamp, PromiseOrValue, log
+    PromiseResult, Timestamp, PromiseOrValue, log, StorageUsage
 };
 
 use once_cell::sync::Lazy;
@@ -61,6 +72,20 @@ pub(crate) enum StorageKeys {
     SeedSlashed,
     SeedLostfound,
     VFarmerSeed { account_id: AccountId },
+    ShadowProvider,
+    ShadowStaker,
+    FarmerIndex,
+    SeedFarmers,
+    SeedFarmer { seed_id: SeedId },
+    FarmCheckpoint,
+    RewardToken,
+    FarmRefund,
+    SponsoredFarmers,
+    SponsoredFarmer { sponsor_id: AccountId },
+    NftLostfound,
+    FarmerSeedLostfound,
+    RewardLostfound,
+    SeedRegisterFee,
 }
 
 /// Contract config
@@ -84,6 +109,27 @@ pub struct Config {
     /// Assuming the 100% multiplier at the 0 duration. Should be no less than 100%.
     /// E.g. 20000 means 200% multiplier (or 2X).
     pub max_locking_multiplier: u32,
+
+    /// The minimum duration in seconds a booster delegation must last before it can be revoked.
+    pub booster_delegation_cooldown_sec: DurationSec,
+
+    /// The interval in seconds between two checkpoints of a farm, 0 means checkpoint disabled.
+    pub farm_checkpoint_interval_sec: DurationSec,
+
+    /// The capacity of each farm's checkpoint ring buffer.
+    pub max_num_farm_checkpoints: u32,
+
+    /// How long in seconds an ended farm stays in seed before anyone can clean it up.
+    pub farm_cleanup_grace_sec: DurationSec,
+
+    /// How long in seconds farmers can still claim from a farm after it was outdated.
+    pub outdated_farm_claim_grace_sec: DurationSec,
+
+    /// Register farmers on their first seed deposit, paid by sponsor pool or register fee of the seed.
+    pub auto_register_farmer: bool,
+
+    /// wNEAR contract wrapping native NEAR reward deposits, None means disabled.
+    pub wnear_id: Option<AccountId>,
 }
 
 impl Default for Config {
@@ -95,6 +141,13 @@ impl Default for Config {
             max_num_farms_per_seed: DEFAULT_MAX_NUM_FARMS_PER_SEED,
             maximum_locking_duration_sec: DEFAULT_MAX_LOCKING_DURATION_SEC,
             max_locking_multiplier: DEFAULT_MAX_LOCKING_REWARD_RATIO,
+            booster_delegation_cooldown_sec: DEFAULT_BOOSTER_DELEGATION_COOLDOWN_SEC,
+            farm_checkpoint_interval_sec: 0,
+            max_num_farm_checkpoints: DEFAULT_MAX_NUM_FARM_CHECKPOINTS,
+            farm_cleanup_grace_sec: DEFAULT_FARM_CLEANUP_GRACE_SEC,
+            outdated_farm_claim_grace_sec: DEFAULT_OUTDATED_FARM_CLAIM_GRACE_SEC,
+            auto_register_farmer: false,
+            wnear_id: None,
         }
     }
 }
@@ -139,11 +192,37 @@ pub struct ContractData {
     pub config: LazyOption<Config>,
     pub seeds: UnorderedMap<SeedId, VSeed>,
     pub farmers: LookupMap<AccountId, VFarmer>,
+    // iterable index of farmers, kept alongside the LookupMap
+    pub farmer_ids: UnorderedSet<AccountId>,
+    // farmers currently staking in each seed
+    pub seed_farmers: LookupMap<SeedId, UnorderedSet<AccountId>>,
+    // farmers registered by each sponsor other than themselves
+    pub sponsored_farmers: LookupMap<AccountId, UnorderedSet<AccountId>>,
+    // periodical snapshots of each farm for analytics
+    pub farm_checkpoints: LookupMap<FarmId, FarmCheckpoints>,
+    // depositors and refund account of each farm, used to return leftovers
+    pub farm_refunds: LookupMap<FarmId, FarmRefundInfo>,
+    // cached metadata of reward tokens, fetched when farm created
+    pub reward_tokens: UnorderedMap<AccountId, RewardTokenMetadata>,
     pub outdated_farms: UnorderedMap<FarmId, VSeedFarm>,
     // all slashed seed would recorded in here
     pub seeds_slashed: UnorderedMap<SeedId, Balance>,
+    // registration fee of auto registered farmers paid in seed token
+    pub seeds_register_fee: UnorderedMap<SeedId, Balance>,
     // if unstake seed encounter error, the seed would go to here
     pub seeds_lostfound: UnorderedMap<SeedId, Balance>,
+    // the same lostfound seed tracked per farmer, so farmers can withdraw it by themselves
+    pub farmer_seeds_lostfound: LookupMap<AccountId, HashMap<SeedId, Balance>>,
+    // reward failed to withdraw after its farmer unregistered, keyed by (farmer_id, token_id)
+    pub rewards_lostfound: UnorderedMap<(AccountId, AccountId), Balance>,
+    // if withdraw nft seed encounter error, the nft would go to here with its owner
+    pub nfts_lostfound: UnorderedMap<(SeedId, String), AccountId>,
+    // contracts allowed to cast shadow seeds besides the built-in ref-exchange v1
+    pub shadow_providers: UnorderedMap<AccountId, ShadowProvider>,
+    // (farmer, seed) pairs holding shadow seed, used for reconciliation with shadow providers
+    pub shadow_stakers: UnorderedSet<(AccountId, SeedId)>,
+    // NEAR reserved to pay registration fee of auto registered farmers
+    pub farmer_sponsor_pool: Balance,
 
     // for statistic
     farmer_count: u64,
@@ -156,7 +235,8 @@ pub enum VersionedContractData {
     V0100(ContractDataV0100),
     V0101(ContractDataV0101),
     V0102(ContractDataV0102),
-    V0103(ContractData),
+    V0103(ContractDataV0103),
+    V0104(ContractData),
 }
 
 #[near_bindgen]
@@ -171,7 +251,7 @@ impl Contract {
     pub fn new(owner_id: AccountId, ref_exchange_id: AccountId) -> Self {
         require!(!env::state_exists(), E000_ALREADY_INIT);
         Self {
-            data: VersionedContractData::V0103(ContractData {
+            data: VersionedContractData::V0104(ContractData {
                 owner_id: owner_id.into(),
                 next_owner_id: None,
                 next_owner_accept_deadline: None,
@@ -181,9 +261,22 @@ impl Contract {
                 config: LazyOption::new(StorageKeys::Config, Some(&Config::default())),
                 seeds: UnorderedMap::new(StorageKeys::Seed),
                 farmers: LookupMap::new(StorageKeys::Farmer),
+                farmer_ids: UnorderedSet::new(StorageKeys::FarmerIndex),
+                seed_farmers: LookupMap::new(StorageKeys::SeedFarmers),
+                sponsored_farmers: LookupMap::new(StorageKeys::SponsoredFarmers),
+                farm_checkpoints: LookupMap::new(StorageKeys::FarmCheckpoint),
+                farm_refunds: LookupMap::new(StorageKeys::FarmRefund),
+                reward_tokens: UnorderedMap::new(StorageKeys::RewardToken),
                 outdated_farms: UnorderedMap::new(StorageKeys::OutdatedFarm),
                 seeds_slashed: UnorderedMap::new(StorageKeys::SeedSlashed),
+                seeds_register_fee: UnorderedMap::new(StorageKeys::SeedRegisterFee),
                 seeds_lostfound: UnorderedMap::new(StorageKeys::SeedLostfound),
+                farmer_seeds_lostfound: LookupMap::new(StorageKeys::FarmerSeedLostfound),
+                rewards_lostfound: UnorderedMap::new(StorageKeys::RewardLostfound),
+                nfts_lostfound: UnorderedMap::new(StorageKeys::NftLostfound),
+                shadow_providers: UnorderedMap::new(StorageKeys::ShadowProvider),
+                shadow_stakers: UnorderedSet::new(StorageKeys::ShadowStaker),
+                farmer_sponsor_pool: 0,
                 farmer_count: 0,
                 farm_count: 0,
             }),
@@ -198,24 +291,33 @@ impl Contract {
 
     fn data(&self) -> &ContractData {
         match &self.data {
-            VersionedContractData::V0103(data) => data,
+            VersionedContractData::V0104(data) => data,
             _ => unimplemented!(),
         }
     }
 
     fn data_mut(&mut self) -> &mut ContractData {
         match &mut self.data {
-            VersionedContractData::V0103(data) => data,
+            VersionedContractData::V0104(data) => data,
             _ => unimplemented!(),
         }
     }
 
     fn is_owner_or_operators(&self) -> bool {
-        env::predecessor_account_id() == self.data().owner_id
+        self.is_owner_or_operator_account(&env::predecessor_account_id())
+    }
+
+    fn is_owner_or_farm_beneficiary(&self, farm_id: &FarmId) -> bool {
+        self.is_owner_or_operators()
+            || self.internal_get_farm_beneficiary(farm_id) == Some(env::predecessor_account_id())
+    }
+
+    fn is_owner_or_operator_account(&self, account_id: &AccountId) -> bool {
+        account_id == &self.data().owner_id
             || self
                 .data()
                 .operators
-                .contains(&env::predecessor_account_id())
+                .contains(account_id)
     }
 }
 
@@ -226,15 +328,41 @@ mod unit_env {
 
     use super::*;
     use near_contract_standards::fungible_token::receiver::FungibleTokenReceiver;
-    use near_contract_standards::storage_management::StorageManagement;
+    pub use near_contract_standards::storage_management::StorageManagement;
     use near_sdk::{json_types::U128, test_utils::accounts};
-    use near_sdk::test_utils::VMContextBuilder;
+    pub use near_sdk::test_utils::VMContextBuilder;
     pub use near_sdk::{testing_env, serde_json, AccountId, Balance};
 
     pub fn d(value: Balance, decimals: u8) -> Balance {
         value * 10u128.pow(decimals as _)
     }
 
+    pub fn contract_id() -> AccountId {
+        "farm".parse().unwrap()
+    }
+
+    /// contract owned by accounts(0), with accounts(1) registered as farmer
+    pub fn setup_contract() -> (VMContextBuilder, Contract) {
+        let mut context = VMContextBuilder::new();
+        context.current_account_id(contract_id());
+        testing_env!(context.predecessor_account_id(accounts(0)).build());
+        let mut contract = Contract::new(accounts(0), "ref_exchange".parse().unwrap());
+        testing_env!(context.predecessor_account_id(accounts(1)).attached_deposit(d(1, 24)).build());
+        contract.storage_deposit(None, None);
+        (context, contract)
+    }
+
+    /// act as the contract's own callback receiving the given promise result
+    pub fn set_promise_result(context: &mut VMContextBuilder, result: PromiseResult) {
+        testing_env!(
+            context.predecessor_account_id(contract_id()).attached_deposit(0).build(),
+            near_sdk::VMConfig::test(),
+            near_sdk::RuntimeFeesConfig::test(),
+            Default::default(),
+            vec![result],
+        );
+    }
+
     #[test]
     fn aa() {
         let mut context = VMContextBuilder::new();
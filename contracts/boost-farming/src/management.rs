@@ -1,5 +1,4 @@
 use crate::*;
-use near_contract_standards::fungible_token::core_impl::ext_fungible_token;
 
 #[near_bindgen]
 impl Contract {
@@ -26,6 +25,9 @@ impl Contract {
             VSeedFarm::V0(farm) => {
                 farm.terms.daily_reward = daily_reward.0;
             }
+            VSeedFarm::V1(farm) => {
+                farm.terms.daily_reward = daily_reward.0;
+            }
             VSeedFarm::Current(farm) => {
                 farm.terms.daily_reward = daily_reward.0;
             }
@@ -34,6 +36,25 @@ impl Contract {
         self.internal_set_seed(&seed_id, seed);
     }
 
+    /// when set, reward distribution pauses while nobody stakes in the seed,
+    /// which extends the farm instead of accumulating reward for beneficiary
+    #[payable]
+    pub fn modify_farm_pause_when_empty(&mut self, farm_id: FarmId, pause_when_empty: bool) {
+        assert_one_yocto();
+        require!(self.is_owner_or_operators(), E002_NOT_ALLOWED);
+        require!(self.data().state == RunningState::Running, E004_CONTRACT_PAUSED);
+
+        let (seed_id, _) = parse_farm_id(&farm_id);
+        let mut seed = self.internal_unwrap_seed(&seed_id);
+
+        let vfarm = seed.farms.remove(&farm_id).expect(E401_FARM_NOT_EXIST);
+        let mut farm: SeedFarm = vfarm.into();
+        farm.terms.options.pause_when_empty = pause_when_empty;
+        seed.farms.insert(farm_id, farm.into());
+
+        self.internal_set_seed(&seed_id, seed);
+    }
+
     #[payable]
     pub fn modify_locking_policy(&mut self, max_duration: DurationSec, max_ratio: u32) {
         assert_one_yocto();
@@ -50,6 +71,96 @@ impl Contract {
         self.data_mut().config.set(&config);
     }
 
+    #[payable]
+    pub fn modify_booster_delegation_cooldown(&mut self, cooldown_sec: DurationSec) {
+        assert_one_yocto();
+        require!(self.is_owner_or_operators(), E002_NOT_ALLOWED);
+        require!(self.data().state == RunningState::Running, E004_CONTRACT_PAUSED);
+
+        let mut config =  self.data().config.get().unwrap();
+        config.booster_delegation_cooldown_sec = cooldown_sec;
+        self.data_mut().config.set(&config);
+    }
+
+    /// set interval_sec to 0 to stop recording farm checkpoints,
+    /// max_num is capped by MAX_NUM_FARM_CHECKPOINTS as checkpoints are stored at contract's expense
+    #[payable]
+    pub fn modify_farm_checkpoint_policy(&mut self, interval_sec: DurationSec, max_num: u32) {
+        assert_one_yocto();
+        require!(self.is_owner_or_operators(), E002_NOT_ALLOWED);
+        require!(self.data().state == RunningState::Running, E004_CONTRACT_PAUSED);
+        require!(max_num > 0 && max_num <= MAX_NUM_FARM_CHECKPOINTS, E213_INVALID_CHECKPOINT_NUM);
+
+        let mut config =  self.data().config.get().unwrap();
+        config.farm_checkpoint_interval_sec = interval_sec;
+        config.max_num_farm_checkpoints = max_num;
+        self.data_mut().config.set(&config);
+    }
+
+    #[payable]
+    pub fn modify_farm_cleanup_grace_period(&mut self, grace_sec: DurationSec) {
+        assert_one_yocto();
+        require!(self.is_owner_or_operators(), E002_NOT_ALLOWED);
+        require!(self.data().state == RunningState::Running, E004_CONTRACT_PAUSED);
+
+        let mut config =  self.data().config.get().unwrap();
+        config.farm_cleanup_grace_sec = grace_sec;
+        self.data_mut().config.set(&config);
+    }
+
+    #[payable]
+    pub fn modify_outdated_farm_claim_grace_period(&mut self, grace_sec: DurationSec) {
+        assert_one_yocto();
+        require!(self.is_owner_or_operators(), E002_NOT_ALLOWED);
+        require!(self.data().state == RunningState::Running, E004_CONTRACT_PAUSED);
+
+        let mut config =  self.data().config.get().unwrap();
+        config.outdated_farm_claim_grace_sec = grace_sec;
+        self.data_mut().config.set(&config);
+    }
+
+    #[payable]
+    pub fn modify_auto_register_farmer(&mut self, enabled: bool) {
+        assert_one_yocto();
+        require!(self.is_owner_or_operators(), E002_NOT_ALLOWED);
+        require!(self.data().state == RunningState::Running, E004_CONTRACT_PAUSED);
+
+        let mut config =  self.data().config.get().unwrap();
+        config.auto_register_farmer = enabled;
+        self.data_mut().config.set(&config);
+    }
+
+    #[payable]
+    pub fn modify_wnear_id(&mut self, wnear_id: Option<AccountId>) {
+        assert_one_yocto();
+        self.assert_owner();
+        require!(self.data().state == RunningState::Running, E004_CONTRACT_PAUSED);
+
+        let mut config =  self.data().config.get().unwrap();
+        config.wnear_id = wnear_id;
+        self.data_mut().config.set(&config);
+    }
+
+    /// anyone can fund the pool paying registration fee of auto registered farmers
+    #[payable]
+    pub fn deposit_farmer_sponsor_pool(&mut self) -> U128 {
+        let amount = env::attached_deposit();
+        require!(amount > 0, E101_INSUFFICIENT_BALANCE);
+        self.data_mut().farmer_sponsor_pool += amount;
+        self.data().farmer_sponsor_pool.into()
+    }
+
+    #[payable]
+    pub fn withdraw_farmer_sponsor_pool(&mut self, amount: U128) -> Promise {
+        assert_one_yocto();
+        self.assert_owner();
+
+        let amount: Balance = amount.into();
+        require!(amount > 0 && amount <= self.data().farmer_sponsor_pool, E101_INSUFFICIENT_BALANCE);
+        self.data_mut().farmer_sponsor_pool -= amount;
+        Promise::new(self.data().owner_id.clone()).transfer(amount)
+    }
+
     #[payable]
     pub fn modify_max_farm_num_per_seed(&mut self, max_num: u32) {
         assert_one_yocto();
@@ -107,6 +218,30 @@ impl Contract {
         seed.slash_rate = slash_rate;
         self.internal_set_seed(&seed_id, seed);
     }
+
+    #[payable]
+    pub fn modify_seed_register_fee(&mut self, seed_id: String, register_fee: U128) {
+        assert_one_yocto();
+        require!(self.is_owner_or_operators(), E002_NOT_ALLOWED);
+        require!(self.data().state == RunningState::Running, E004_CONTRACT_PAUSED);
+
+        let mut seed = self.internal_unwrap_seed(&seed_id);
+        seed.register_fee = register_fee.into();
+        self.internal_set_seed(&seed_id, seed);
+    }
+
+    /// set how staked nfts of a nft seed are valued, None stops accepting new nfts
+    #[payable]
+    pub fn modify_seed_nft_valuation(&mut self, seed_id: String, nft_valuation: Option<NftValuation>) {
+        assert_one_yocto();
+        require!(self.is_owner_or_operators(), E002_NOT_ALLOWED);
+        require!(self.data().state == RunningState::Running, E004_CONTRACT_PAUSED);
+        require!(is_nft_seed_id(&seed_id), E308_INVALID_SEED_ID);
+
+        let mut seed = self.internal_unwrap_seed(&seed_id);
+        seed.nft_valuation = nft_valuation;
+        self.internal_set_seed(&seed_id, seed);
+    }
     
     /// Owner retrieve those slashed seed
     #[payable]
@@ -119,18 +254,7 @@ impl Contract {
         let amount = self.data_mut().seeds_slashed.remove(&seed_id).unwrap_or(0_u128);
         require!(amount > 0, E101_INSUFFICIENT_BALANCE);
 
-        let (token, token_id) = parse_seed_id(&seed_id);
-
-        if let Some(token_id) = token_id {
-            ext_multi_fungible_token::mft_transfer(
-                wrap_mft_token_id(&token_id),
-                self.data().owner_id.clone(),
-                amount.into(),
-                None,
-                token,
-                1, // one yocto near
-                GAS_FOR_SEED_TRANSFER,
-            )
+        self.internal_seed_transfer(&self.data().owner_id, &seed_id, amount)
             .then(ext_self::callback_withdraw_seed_slashed(
                 seed_id.clone(),
                 amount.into(),
@@ -138,27 +262,31 @@ impl Contract {
                 0,
                 GAS_FOR_RESOLVE_SEED_TRANSFER,
             ))
-        } else {
-            ext_fungible_token::ft_transfer(
-                self.data().owner_id.clone(),
-                amount.into(),
-                None,
-                token,
-                1, // one yocto near
-                GAS_FOR_SEED_TRANSFER,
-            )
-            .then(ext_self::callback_withdraw_seed_slashed(
+    }
+
+    /// Owner retrieve registration fee of auto registered farmers paid in seed token
+    #[payable]
+    pub fn withdraw_seed_register_fee(&mut self, seed_id: SeedId) -> Promise {
+        assert_one_yocto();
+        require!(self.is_owner_or_operators(), E002_NOT_ALLOWED);
+        require!(self.data().state == RunningState::Running, E004_CONTRACT_PAUSED);
+
+        let amount = self.data_mut().seeds_register_fee.remove(&seed_id).unwrap_or(0_u128);
+        require!(amount > 0, E101_INSUFFICIENT_BALANCE);
+
+        self.internal_seed_transfer(&self.data().owner_id, &seed_id, amount)
+            .then(ext_self::callback_withdraw_seed_register_fee(
                 seed_id.clone(),
                 amount.into(),
                 env::current_account_id(),
                 0,
                 GAS_FOR_RESOLVE_SEED_TRANSFER,
             ))
-        }
     }
 
     /// owner help to return those who lost seed when withdraw,
-    /// It's owner's responsibility to verify amount and seed id before calling
+    /// It's owner's responsibility to verify amount and seed id before calling,
+    /// farmers can withdraw lostfound tracked for them through withdraw_lostfound instead
     #[payable]
     pub fn return_seed_lostfound(&mut self, farmer_id: AccountId, seed_id: SeedId, amount: U128) -> Promise {
         assert_one_yocto();
@@ -171,19 +299,10 @@ impl Contract {
         let max_amount = self.data().seeds_lostfound.get(&seed_id).unwrap_or(0_u128);
         require!(amount.0 <= max_amount, E101_INSUFFICIENT_BALANCE);
         self.data_mut().seeds_lostfound.insert(&seed_id, &(max_amount - amount.0));
+        // so the same amount can not be withdrawn again by the farmer through withdraw_lostfound
+        self.internal_deduct_farmer_seed_lostfound(&farmer_id, &seed_id, amount.0);
 
-        let (token, token_id) = parse_seed_id(&seed_id);
-
-        if let Some(token_id) = token_id {
-            ext_multi_fungible_token::mft_transfer(
-                wrap_mft_token_id(&token_id),
-                farmer_id.clone(),
-                amount.into(),
-                None,
-                token,
-                1, // one yocto near
-                GAS_FOR_SEED_TRANSFER,
-            )
+        self.internal_seed_transfer(&farmer_id, &seed_id, amount.0)
             .then(ext_self::callback_withdraw_seed_lostfound(
                 seed_id.clone(),
                 farmer_id.clone(),
@@ -192,30 +311,13 @@ impl Contract {
                 0,
                 GAS_FOR_RESOLVE_SEED_TRANSFER,
             ))
-        } else {
-            ext_fungible_token::ft_transfer(
-                farmer_id.clone(),
-                amount.into(),
-                None,
-                token,
-                1, // one yocto near
-                GAS_FOR_SEED_TRANSFER,
-            )
-            .then(ext_self::callback_withdraw_seed_lostfound(
-                seed_id.clone(),
-                farmer_id.clone(),
-                amount.into(),
-                env::current_account_id(),
-                0,
-                GAS_FOR_RESOLVE_SEED_TRANSFER,
-            ))
-        }
     }
 
+    /// can also be called by beneficiary_id of the farm
     #[payable]
     pub fn withdraw_from_beneficiary_account(&mut self, farm_id: FarmId) -> Promise {
         assert_one_yocto();
-        require!(self.is_owner_or_operators(), E002_NOT_ALLOWED);
+        require!(self.is_owner_or_farm_beneficiary(&farm_id), E002_NOT_ALLOWED);
         require!(self.data().state == RunningState::Running, E004_CONTRACT_PAUSED);
         
         let (seed_id, _) = parse_farm_id(&farm_id);
@@ -226,6 +328,9 @@ impl Contract {
             VSeedFarm::V0(farm) => {
                 farm.into()
             }
+            VSeedFarm::V1(farm) => {
+                farm.into()
+            }
             VSeedFarm::Current(farm) => {
                 farm
             }
@@ -234,31 +339,19 @@ impl Contract {
         let amount = seed_farm.amount_of_beneficiary - seed_farm.amount_of_withdrew_beneficiary;
         require!(amount > 0, E101_INSUFFICIENT_BALANCE);
         let reward_token = seed_farm.terms.reward_token.clone();
+        let receivers = self.internal_get_beneficiary_receivers(&seed_farm, amount);
         seed_farm.amount_of_withdrew_beneficiary = seed_farm.amount_of_beneficiary;
         seed.farms.insert(farm_id.clone(), seed_farm.into());
         self.internal_set_seed(&seed_id, seed);
 
-        ext_fungible_token::ft_transfer(
-            self.data().owner_id.clone(),
-            amount.into(),
-            None,
-            reward_token,
-            1, // one yocto near
-            GAS_FOR_REWARD_TRANSFER,
-        )
-        .then(ext_self::callback_withdraw_beneficiary(
-            farm_id.clone(),
-            amount.into(),
-            env::current_account_id(),
-            0,
-            GAS_FOR_RESOLVE_REWARD_TRANSFER,
-        ))
+        self.internal_transfer_refunds(&farm_id, &reward_token, receivers, ext_self::callback_withdraw_beneficiary)
     }
 
+    /// can also be called by beneficiary_id of the farm
     #[payable]
     pub fn withdraw_from_outdated_beneficiary_account(&mut self, farm_id: FarmId) -> Promise {
         assert_one_yocto();
-        require!(self.is_owner_or_operators(), E002_NOT_ALLOWED);
+        require!(self.is_owner_or_farm_beneficiary(&farm_id), E002_NOT_ALLOWED);
         require!(self.data().state == RunningState::Running, E004_CONTRACT_PAUSED);
         
         let v_farm = self.data_mut().outdated_farms.remove(&farm_id).expect(E401_FARM_NOT_EXIST);
@@ -266,32 +359,26 @@ impl Contract {
             VSeedFarm::V0(farm) => {
                 farm.into()
             }
+            VSeedFarm::V1(farm) => {
+                farm.into()
+            }
             VSeedFarm::Current(farm) => {
                 farm
             }
         };
+        if seed_farm.claimable_until > 0 && !seed_farm.is_claimable() {
+            // claim grace period is over, unclaimed rewards go to beneficiary
+            seed_farm.finalize();
+        }
 
         let amount = seed_farm.amount_of_beneficiary - seed_farm.amount_of_withdrew_beneficiary;
         require!(amount > 0, E101_INSUFFICIENT_BALANCE);
         let reward_token = seed_farm.terms.reward_token.clone();
+        let receivers = self.internal_get_beneficiary_receivers(&seed_farm, amount);
         seed_farm.amount_of_withdrew_beneficiary = seed_farm.amount_of_beneficiary;
         self.data_mut().outdated_farms.insert(&farm_id, &seed_farm.into());
 
-        ext_fungible_token::ft_transfer(
-            self.data().owner_id.clone(),
-            amount.into(),
-            None,
-            reward_token,
-            1, // one yocto near
-            GAS_FOR_REWARD_TRANSFER,
-        )
-        .then(ext_self::callback_withdraw_outdated_beneficiary(
-            farm_id.clone(),
-            amount.into(),
-            env::current_account_id(),
-            0,
-            GAS_FOR_RESOLVE_REWARD_TRANSFER,
-        ))
+        self.internal_transfer_refunds(&farm_id, &reward_token, receivers, ext_self::callback_withdraw_outdated_beneficiary)
     }
 
     #[payable]
@@ -310,6 +397,9 @@ impl Contract {
             VSeedFarm::V0(farm) => {
                 farm.into()
             }
+            VSeedFarm::V1(farm) => {
+                farm.into()
+            }
             VSeedFarm::Current(farm) => {
                 farm
             }
@@ -325,21 +415,8 @@ impl Contract {
         seed.farms.insert(farm_id.clone(), seed_farm.into());
         self.internal_set_seed(&seed_id, seed);
 
-        ext_fungible_token::ft_transfer(
-            self.data().owner_id.clone(),
-            amount.into(),
-            None,
-            reward_token,
-            1, // one yocto near
-            GAS_FOR_REWARD_TRANSFER,
-        )
-        .then(ext_self::callback_withdraw_undistributed(
-            farm_id.clone(),
-            amount.into(),
-            env::current_account_id(),
-            0,
-            GAS_FOR_RESOLVE_REWARD_TRANSFER,
-        ))
+        let receivers = self.internal_get_refund_receivers(&farm_id, amount);
+        self.internal_transfer_refunds(&farm_id, &reward_token, receivers, ext_self::callback_withdraw_undistributed)
     }
 
     #[private]
@@ -353,10 +430,7 @@ impl Contract {
             PromiseResult::NotReady => unreachable!(),
             PromiseResult::Failed => {
                 // all seed amount go to lostfound
-                let seed_amount = self.data().seeds_lostfound.get(&seed_id).unwrap_or(0);
-                self.data_mut()
-                    .seeds_lostfound
-                    .insert(&seed_id, &(seed_amount + amount));
+                self.internal_add_seed_lostfound(&sender_id, &seed_id, amount);
 
                 Event::SeedWithdrawLostfound {
                     farmer_id: &sender_id,
@@ -412,8 +486,34 @@ impl Contract {
         }
     }
 
+    /// if withdraw seed register fee encounter async error, it would go back to seeds_register_fee
+    #[private]
+    pub fn callback_withdraw_seed_register_fee(&mut self, seed_id: SeedId, amount: U128) {
+        require!(
+            env::promise_results_count() == 1,
+            E001_PROMISE_RESULT_COUNT_INVALID
+        );
+        let amount: Balance = amount.into();
+        let success = match env::promise_result(0) {
+            PromiseResult::NotReady => unreachable!(),
+            PromiseResult::Failed => {
+                let seed_amount = self.data().seeds_register_fee.get(&seed_id).unwrap_or(0);
+                self.data_mut().seeds_register_fee.insert(&seed_id, &(seed_amount + amount));
+                false
+            }
+            PromiseResult::Successful(_) => true,
+        };
+        Event::SeedWithdrawRegisterFee {
+            owner_id: &self.data().owner_id,
+            seed_id: &seed_id,
+            withdraw_amount: &U128(amount),
+            success,
+        }
+        .emit();
+    }
+
     #[private]
-    pub fn callback_withdraw_beneficiary(&mut self, farm_id: FarmId, amount: U128) {
+    pub fn callback_withdraw_beneficiary(&mut self, farm_id: FarmId, receiver_id: AccountId, amount: U128) {
         require!(
             env::promise_results_count() == 1,
             E001_PROMISE_RESULT_COUNT_INVALID
@@ -435,6 +535,7 @@ impl Contract {
 
                 Event::RewardWithdrawBeneficiary {
                     owner_id: &self.data().owner_id,
+                    receiver_id: &receiver_id,
                     farm_id: &farm_id,
                     withdraw_amount: &U128(amount),
                     success: false,
@@ -444,6 +545,7 @@ impl Contract {
             PromiseResult::Successful(_) => {
                 Event::RewardWithdrawBeneficiary {
                     owner_id: &self.data().owner_id,
+                    receiver_id: &receiver_id,
                     farm_id: &farm_id,
                     withdraw_amount: &U128(amount),
                     success: true,
@@ -454,7 +556,7 @@ impl Contract {
     }
 
     #[private]
-    pub fn callback_withdraw_outdated_beneficiary(&mut self, farm_id: FarmId, amount: U128) {
+    pub fn callback_withdraw_outdated_beneficiary(&mut self, farm_id: FarmId, receiver_id: AccountId, amount: U128) {
         require!(
             env::promise_results_count() == 1,
             E001_PROMISE_RESULT_COUNT_INVALID
@@ -472,6 +574,7 @@ impl Contract {
 
                 Event::RewardWithdrawOutdatedBeneficiary {
                     owner_id: &self.data().owner_id,
+                    receiver_id: &receiver_id,
                     farm_id: &farm_id,
                     withdraw_amount: &U128(amount),
                     success: false,
@@ -481,6 +584,7 @@ impl Contract {
             PromiseResult::Successful(_) => {
                 Event::RewardWithdrawOutdatedBeneficiary {
                     owner_id: &self.data().owner_id,
+                    receiver_id: &receiver_id,
                     farm_id: &farm_id,
                     withdraw_amount: &U128(amount),
                     success: true,
@@ -491,7 +595,7 @@ impl Contract {
     }
 
     #[private]
-    pub fn callback_withdraw_undistributed(&mut self, farm_id: FarmId, amount: U128) {
+    pub fn callback_withdraw_undistributed(&mut self, farm_id: FarmId, receiver_id: AccountId, amount: U128) {
         require!(
             env::promise_results_count() == 1,
             E001_PROMISE_RESULT_COUNT_INVALID
@@ -514,6 +618,7 @@ impl Contract {
 
                 Event::RewardWithdrawUndistributed {
                     owner_id: &self.data().owner_id,
+                    receiver_id: &receiver_id,
                     farm_id: &farm_id,
                     withdraw_amount: &U128(amount),
                     success: false,
@@ -521,8 +626,11 @@ impl Contract {
                 .emit();
             },
             PromiseResult::Successful(_) => {
+                self.internal_deduct_reward_deposit(&farm_id, &receiver_id, amount);
+
                 Event::RewardWithdrawUndistributed {
                     owner_id: &self.data().owner_id,
+                    receiver_id: &receiver_id,
                     farm_id: &farm_id,
                     withdraw_amount: &U128(amount),
                     success: true,
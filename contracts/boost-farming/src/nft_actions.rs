@@ -0,0 +1,288 @@
+use crate::*;
+use near_contract_standards::non_fungible_token::core::NonFungibleTokenReceiver;
+use near_sdk::serde_json::{self, json, Value};
+
+/// Message parameters to receive via nft_transfer_call.
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+enum NftReceiverMessage {
+    Free { seed_id: SeedId },
+}
+
+/// How a staked nft is valued into seed amount, e.g. liquidity of a concentrated-liquidity position.
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
+pub enum NftValuation {
+    /// call `<method>({"token_id": <token_id>})` on nft contract, which returns seed amount in U128
+    Callback { method: String },
+    /// read seed amount from `field` of json `metadata.extra` returned by nft contract's `nft_token`
+    MetadataExtra { field: String },
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+struct NftTokenView {
+    metadata: Option<NftMetadataView>,
+}
+
+#[derive(Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+struct NftMetadataView {
+    extra: Option<String>,
+}
+
+impl NftValuation {
+    /// parse valuation call result into seed amount, None if not recognized
+    pub fn parse_amount(&self, value: &[u8]) -> Option<Balance> {
+        match self {
+            NftValuation::Callback { .. } => serde_json::from_slice::<U128>(value).ok().map(|v| v.0),
+            NftValuation::MetadataExtra { field } => {
+                let token = serde_json::from_slice::<Option<NftTokenView>>(value).ok()??;
+                let extra = token.metadata?.extra?;
+                let extra = serde_json::from_str::<HashMap<String, Value>>(&extra).ok()?;
+                match extra.get(field)? {
+                    Value::String(v) => v.parse().ok(),
+                    Value::Number(v) => v.as_u64().map(Balance::from),
+                    _ => None,
+                }
+            }
+        }
+    }
+}
+
+#[near_bindgen]
+impl NonFungibleTokenReceiver for Contract {
+    /// Stake the received nft into given nft seed for its previous owner,
+    /// the seed amount is decided by seed's nft_valuation asynchronously.
+    /// sender_id may be an approved operator, it is kept only as the NEP-171 arg name.
+    #[allow(unused_variables)]
+    fn nft_on_transfer(
+        &mut self,
+        sender_id: AccountId,
+        previous_owner_id: AccountId,
+        token_id: String,
+        msg: String,
+    ) -> PromiseOrValue<bool> {
+        require!(self.data().state == RunningState::Running, E004_CONTRACT_PAUSED);
+        let NftReceiverMessage::Free { seed_id } =
+            serde_json::from_str::<NftReceiverMessage>(&msg).expect(E500_INVALID_MSG);
+        require!(is_nft_seed_id(&seed_id), E308_INVALID_SEED_ID);
+        let (nft_contract_id, token_id_prefix) = parse_seed_id(&seed_id);
+        require!(nft_contract_id == env::predecessor_account_id(), E308_INVALID_SEED_ID);
+        if let Some(token_id_prefix) = token_id_prefix {
+            require!(token_id.starts_with(&token_id_prefix), E308_INVALID_SEED_ID);
+        }
+        let valuation = self.internal_unwrap_seed(&seed_id).nft_valuation.expect(E603_NFT_VALUATION_NOT_SET);
+
+        // nft can not be split, so only sponsor pool can pay for registration
+        self.internal_auto_register_farmer(&previous_owner_id, &seed_id, 0, false);
+        self.internal_unwrap_farmer(&previous_owner_id);
+
+        let valuation_promise = match valuation {
+            NftValuation::Callback { method } => Promise::new(nft_contract_id).function_call(
+                method,
+                json!({ "token_id": token_id }).to_string().into_bytes(),
+                0,
+                GAS_FOR_NFT_VALUATION,
+            ),
+            NftValuation::MetadataExtra { .. } => ext_non_fungible_token::nft_token(
+                token_id.clone(),
+                nft_contract_id,
+                0,
+                GAS_FOR_NFT_VALUATION,
+            ),
+        };
+        valuation_promise
+            .then(ext_self::callback_stake_nft(
+                seed_id,
+                previous_owner_id,
+                token_id,
+                env::current_account_id(),
+                0,
+                GAS_FOR_NFT_STAKE_CALLBACK,
+            ))
+            .into()
+    }
+}
+
+#[near_bindgen]
+impl Contract {
+    /// return true to give back the nft to its owner when valuation fails
+    #[private]
+    pub fn callback_stake_nft(&mut self, seed_id: SeedId, farmer_id: AccountId, token_id: String) -> bool {
+        require!(
+            env::promise_results_count() == 1,
+            E001_PROMISE_RESULT_COUNT_INVALID
+        );
+        let valuation = self.internal_unwrap_seed(&seed_id).nft_valuation.expect(E603_NFT_VALUATION_NOT_SET);
+        let amount = match env::promise_result(0) {
+            PromiseResult::NotReady => unreachable!(),
+            PromiseResult::Failed => None,
+            PromiseResult::Successful(value) => valuation.parse_amount(&value),
+        };
+        match amount {
+            Some(amount) if amount > 0 => {
+                self.stake_nft_seed(&farmer_id, &seed_id, &token_id, amount);
+                false
+            }
+            _ => true,
+        }
+    }
+
+    /// withdraw a staked nft, its valued seed amount must be free
+    #[payable]
+    pub fn withdraw_nft_seed(&mut self, seed_id: SeedId, token_id: String) -> Promise {
+        assert_one_yocto();
+        require!(self.data().state == RunningState::Running, E004_CONTRACT_PAUSED);
+
+        let farmer_id = env::predecessor_account_id();
+
+        let mut farmer = self.internal_unwrap_farmer(&farmer_id);
+        self.internal_do_farmer_claim(&mut farmer, &seed_id);
+        self.sync_booster_policy(&mut farmer);
+        let mut seed = self.internal_unwrap_seed(&seed_id);
+
+        let mut farmer_seed = farmer.get_seed_unwrap(&seed_id);
+        let (amount, decreased_seed_power) = farmer_seed.withdraw_nft(&token_id);
+        farmer.assert_delegation_covered(&seed_id, &farmer_seed);
+
+        seed.total_seed_amount -= amount;
+        seed.total_seed_power -= decreased_seed_power;
+
+        self.internal_set_farmer_seed(&mut farmer, &mut seed, farmer_seed);
+
+        self.update_impacted_seeds(&mut farmer, &seed_id);
+
+        self.internal_set_farmer(&farmer_id, farmer);
+        self.internal_set_seed(&seed_id, seed);
+
+        self.internal_nft_transfer(&farmer_id, &seed_id, &token_id)
+    }
+
+    /// owner help to return nft which failed to be transferred when withdraw
+    #[payable]
+    pub fn return_nft_lostfound(&mut self, seed_id: SeedId, token_id: String) -> Promise {
+        assert_one_yocto();
+        self.assert_owner();
+        require!(self.data().state == RunningState::Running, E004_CONTRACT_PAUSED);
+
+        let farmer_id = self.data_mut().nfts_lostfound.remove(&(seed_id.clone(), token_id.clone())).expect(E607_NFT_NOT_IN_LOSTFOUND);
+
+        self.internal_nft_transfer(&farmer_id, &seed_id, &token_id)
+    }
+
+    #[private]
+    pub fn callback_withdraw_nft(&mut self, seed_id: SeedId, farmer_id: AccountId, token_id: String) -> bool {
+        require!(
+            env::promise_results_count() == 1,
+            E001_PROMISE_RESULT_COUNT_INVALID
+        );
+        match env::promise_result(0) {
+            PromiseResult::NotReady => unreachable!(),
+            PromiseResult::Failed => {
+                // nft goes to lostfound and waits for owner to return it
+                self.data_mut().nfts_lostfound.insert(&(seed_id.clone(), token_id.clone()), &farmer_id);
+
+                Event::NftSeedWithdraw {
+                    farmer_id: &farmer_id,
+                    seed_id: &seed_id,
+                    token_id: &token_id,
+                    success: false,
+                }
+                .emit();
+                false
+            }
+            PromiseResult::Successful(_) => {
+                Event::NftSeedWithdraw {
+                    farmer_id: &farmer_id,
+                    seed_id: &seed_id,
+                    token_id: &token_id,
+                    success: true,
+                }
+                .emit();
+                true
+            }
+        }
+    }
+}
+
+impl Contract {
+    fn stake_nft_seed(&mut self, farmer_id: &AccountId, seed_id: &SeedId, token_id: &str, amount: Balance) {
+        let mut farmer = self.internal_unwrap_farmer(farmer_id);
+        self.internal_do_farmer_claim(&mut farmer, seed_id);
+        self.sync_booster_policy(&mut farmer);
+        let mut seed = self.internal_unwrap_seed(seed_id);
+        require!(amount >= seed.min_deposit, E307_BELOW_MIN_DEPOSIT);
+
+        let mut farmer_seed = farmer.get_seed_unwrap(seed_id);
+        let increased_seed_power = farmer_seed.add_nft(token_id, amount);
+        self.internal_set_farmer_seed(&mut farmer, &mut seed, farmer_seed);
+
+        seed.total_seed_amount += amount;
+        seed.total_seed_power += increased_seed_power;
+
+        self.update_impacted_seeds(&mut farmer, seed_id);
+
+        self.internal_set_farmer(farmer_id, farmer);
+        self.internal_set_seed(seed_id, seed);
+
+        Event::NftSeedDeposit {
+            farmer_id,
+            seed_id,
+            token_id,
+            deposit_amount: &U128(amount),
+            increased_power: &U128(increased_seed_power),
+        }
+        .emit();
+    }
+
+    fn internal_nft_transfer(&self, farmer_id: &AccountId, seed_id: &SeedId, token_id: &str) -> Promise {
+        let (nft_contract_id, _) = parse_seed_id(seed_id);
+        ext_non_fungible_token::nft_transfer(
+            farmer_id.clone(),
+            token_id.to_string(),
+            None,
+            None,
+            nft_contract_id,
+            1, // one yocto near
+            GAS_FOR_NFT_TRANSFER,
+        )
+        .then(ext_self::callback_withdraw_nft(
+            seed_id.clone(),
+            farmer_id.clone(),
+            token_id.to_string(),
+            env::current_account_id(),
+            0,
+            GAS_FOR_RESOLVE_NFT_TRANSFER,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod nft_tests {
+    use super::*;
+
+    #[test]
+    fn nft_seed() {
+        let (token, token_id_prefix) = parse_seed_id(&"nft:dcl".to_string());
+        assert_eq!(token.to_string(), "dcl");
+        assert_eq!(token_id_prefix, None);
+        let (token, token_id_prefix) = parse_seed_id(&"nft:dcl@usdc|usdt|100#".to_string());
+        assert_eq!(token.to_string(), "dcl");
+        assert_eq!(token_id_prefix, Some("usdc|usdt|100#".to_string()));
+
+        let valuation = NftValuation::Callback { method: "get_liquidity".to_string() };
+        assert_eq!(valuation.parse_amount(b"\"1000\""), Some(1000));
+        assert_eq!(valuation.parse_amount(b"null"), None);
+
+        let valuation = NftValuation::MetadataExtra { field: "liquidity".to_string() };
+        let token = serde_json::json!({
+            "token_id": "usdc|usdt|100#1",
+            "owner_id": "alice",
+            "metadata": { "extra": "{\"liquidity\":\"1000\"}" },
+        });
+        assert_eq!(valuation.parse_amount(token.to_string().as_bytes()), Some(1000));
+        assert_eq!(valuation.parse_amount(b"null"), None);
+    }
+}
@@ -164,11 +164,72 @@ impl Contract {
         }.emit();
     }
 
+    /// Register or replace a contract that can cast shadow seeds. Only can be called by owner.
+    #[payable]
+    pub fn set_shadow_provider(&mut self, provider_id: AccountId, shadow_provider: ShadowProvider) {
+        assert_one_yocto();
+        self.assert_owner();
+        require!(!shadow_provider.shadow_prefix.is_empty(), E701_INVALID_SHADOW_ID);
+        for seed_id in &shadow_provider.allowed_seeds {
+            require!(self.data().seeds.get(seed_id).is_some(), E301_SEED_NOT_EXIST);
+        }
+        Event::SetShadowProvider {
+            provider_id: &provider_id,
+            shadow_prefix: &shadow_provider.shadow_prefix,
+            allowed_seeds: &shadow_provider.allowed_seeds,
+        }.emit();
+        self.data_mut().shadow_providers.insert(&provider_id, &shadow_provider);
+    }
+
+    /// Existing shadow stakes stay in farm, farmers or owner can drop them through remove_orphan_shadow.
+    #[payable]
+    pub fn remove_shadow_provider(&mut self, provider_id: AccountId) {
+        assert_one_yocto();
+        self.assert_owner();
+        require!(self.data_mut().shadow_providers.remove(&provider_id).is_some(), E700_SHADOW_PROVIDER_NOT_EXIST);
+        Event::RemoveShadowProvider {
+            provider_id: &provider_id,
+        }.emit();
+    }
+
+    /// Farmers stored before the farmer index exist only in a LookupMap that migrate can not iterate,
+    /// so owner feeds their ids here in batches, collected from off-chain indexers.
+    /// Their existing seeds are counted into seed statistics, their sponsors and shadow stakes are indexed at the same time.
+    #[payable]
+    pub fn backfill_farmer_index(&mut self, farmer_ids: Vec<AccountId>) {
+        assert_one_yocto();
+        self.assert_owner();
+        for farmer_id in farmer_ids {
+            if let Some(mut farmer) = self.internal_get_farmer(&farmer_id) {
+                // index entries are counted into the farmer as on registration, repeated ids change nothing
+                let prev_storage = env::storage_usage();
+                self.data_mut().farmer_ids.insert(&farmer_id);
+                self.internal_update_sponsored_farmer(&farmer.sponsor_id, &farmer_id, true);
+                farmer.update_storage_usage(prev_storage);
+                let seed_ids: Vec<SeedId> = farmer.seeds.keys().chain(farmer.vseeds.keys()).collect();
+                for seed_id in seed_ids {
+                    let farmer_seed = farmer.get_seed_unwrap(&seed_id);
+                    self.internal_update_shadow_staker(&mut farmer, &seed_id, &farmer_seed);
+                    if !farmer_seed.is_empty() && !self.internal_is_seed_farmer(&farmer_id, &seed_id) {
+                        let mut seed = self.internal_unwrap_seed(&seed_id);
+                        seed.add_farmer_stats(&farmer_seed);
+                        self.internal_set_seed(&seed_id, seed);
+                        let prev_storage = env::storage_usage();
+                        self.internal_update_seed_farmer(&farmer_id, &seed_id, true);
+                        farmer.update_storage_usage(prev_storage);
+                    }
+                }
+                self.internal_save_farmer(&farmer_id, farmer);
+            }
+        }
+    }
+
     /// Should only be called by this contract on migration.
     /// This is NOOP implementation. KEEP IT if you haven't changed contract state.
     /// If you have, you need to implement migration from old state 
     /// (keep the old struct with different name to deserialize it first).
     /// After migration goes live, revert back to this implementation for next updates.
+    /// NOTE: farmer index can not be built here, owner must call backfill_farmer_index after upgrade.
     #[init(ignore_state)]
     #[private]
     pub fn migrate() -> Self {
@@ -176,10 +237,11 @@ impl Contract {
         // see if ContractData need upgrade
         contract.data = 
         match contract.data {
-            VersionedContractData::V0100(data) => VersionedContractData::V0103(data.into()),
-            VersionedContractData::V0101(data) => VersionedContractData::V0103(data.into()),
-            VersionedContractData::V0102(data) => VersionedContractData::V0103(data.into()),
-            VersionedContractData::V0103(data) => VersionedContractData::V0103(data),
+            VersionedContractData::V0100(data) => VersionedContractData::V0104(data.into()),
+            VersionedContractData::V0101(data) => VersionedContractData::V0104(data.into()),
+            VersionedContractData::V0102(data) => VersionedContractData::V0104(data.into()),
+            VersionedContractData::V0103(data) => VersionedContractData::V0104(data.into()),
+            VersionedContractData::V0104(data) => VersionedContractData::V0104(data),
         };
         contract
     }
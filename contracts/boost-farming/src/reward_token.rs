@@ -0,0 +1,133 @@
+use crate::*;
+
+use near_contract_standards::fungible_token::metadata::FungibleTokenMetadata;
+use near_sdk::{serde_json, Gas};
+
+pub const GAS_FOR_FT_METADATA: Gas = Gas(10 * TGAS);
+pub const GAS_FOR_FT_METADATA_CALLBACK: Gas = Gas(10 * TGAS);
+
+/// Metadata of a reward token, fetched from its ft_metadata.
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Clone)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug, Deserialize, PartialEq))]
+#[serde(crate = "near_sdk::serde")]
+pub struct RewardTokenMetadata {
+    pub symbol: String,
+    pub decimals: u8,
+}
+
+impl Contract {
+    pub fn internal_get_reward_token_metadata(&self, token_id: &AccountId) -> Option<RewardTokenMetadata> {
+        self.data().reward_tokens.get(token_id)
+    }
+
+    /// fire a detached ft_metadata call, the result is stored by callback_ft_metadata
+    pub fn internal_fetch_reward_token_metadata(&self, token_id: &AccountId) -> Promise {
+        ext_fungible_token_metadata::ft_metadata(
+            token_id.clone(),
+            0,
+            GAS_FOR_FT_METADATA,
+        )
+        .then(ext_self::callback_ft_metadata(
+            token_id.clone(),
+            env::current_account_id(),
+            0,
+            GAS_FOR_FT_METADATA_CALLBACK,
+        ))
+    }
+
+    /// attach cached reward token metadata to a farm for views
+    pub fn internal_fill_farm_metadata(&self, mut farm: SeedFarm) -> SeedFarm {
+        farm.reward_token_metadata = self.internal_get_reward_token_metadata(&farm.terms.reward_token);
+        farm
+    }
+}
+
+#[near_bindgen]
+impl Contract {
+    /// re-fetch metadata of given reward tokens, eg: after a token changed its symbol.
+    #[payable]
+    pub fn refresh_reward_token_metadata(&mut self, token_ids: Vec<AccountId>) {
+        assert_one_yocto();
+        require!(self.is_owner_or_operators(), E002_NOT_ALLOWED);
+        for token_id in token_ids.iter() {
+            self.internal_fetch_reward_token_metadata(token_id);
+        }
+    }
+
+    #[private]
+    pub fn callback_ft_metadata(&mut self, token_id: AccountId) {
+        require!(
+            env::promise_results_count() == 1,
+            E001_PROMISE_RESULT_COUNT_INVALID
+        );
+        let metadata = match env::promise_result(0) {
+            PromiseResult::NotReady => unreachable!(),
+            PromiseResult::Failed => None,
+            PromiseResult::Successful(value) => {
+                serde_json::from_slice::<FungibleTokenMetadata>(&value).ok()
+            }
+        };
+        if let Some(metadata) = metadata {
+            let reward_token_metadata = RewardTokenMetadata {
+                symbol: metadata.symbol,
+                decimals: metadata.decimals,
+            };
+            self.data_mut().reward_tokens.insert(&token_id, &reward_token_metadata);
+
+            Event::RewardTokenMetadataUpdate {
+                token_id: &token_id,
+                symbol: &reward_token_metadata.symbol,
+                decimals: reward_token_metadata.decimals,
+            }
+            .emit();
+        } else {
+            log!("Failed to fetch metadata of reward token {}", token_id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod reward_token_tests {
+    use super::*;
+    use crate::unit_env::*;
+    use near_sdk::test_utils::accounts;
+
+    #[test]
+    fn reward_token_metadata() {
+        let (mut context, mut contract) = setup_contract();
+        let metadata = serde_json::json!({
+            "spec": "ft-1.0.0",
+            "name": "Ref Finance Token",
+            "symbol": "REF",
+            "icon": null,
+            "reference": null,
+            "reference_hash": null,
+            "decimals": 18,
+        });
+        let ref_metadata = RewardTokenMetadata { symbol: "REF".to_string(), decimals: 18 };
+
+        // success
+        set_promise_result(&mut context, PromiseResult::Successful(metadata.to_string().into_bytes()));
+        contract.callback_ft_metadata(accounts(2));
+        assert_eq!(contract.get_reward_token_metadata(accounts(2)), Some(ref_metadata.clone()));
+
+        // failed or malformed result keeps what was cached
+        set_promise_result(&mut context, PromiseResult::Failed);
+        contract.callback_ft_metadata(accounts(3));
+        assert_eq!(contract.get_reward_token_metadata(accounts(3)), None);
+        set_promise_result(&mut context, PromiseResult::Successful(b"null".to_vec()));
+        contract.callback_ft_metadata(accounts(2));
+        assert_eq!(contract.get_reward_token_metadata(accounts(2)), Some(ref_metadata.clone()));
+
+        let mut farmer = contract.internal_unwrap_farmer(&accounts(1));
+        farmer.add_rewards(&HashMap::from([(accounts(2), 100), (accounts(3), 50)]));
+        contract.internal_set_farmer(&accounts(1), farmer);
+        let rewards = contract.list_farmer_rewards_with_metadata(accounts(1));
+        assert_eq!(rewards.len(), 2);
+        assert_eq!(rewards[&accounts(2)].amount.0, 100);
+        assert_eq!(rewards[&accounts(2)].metadata, Some(ref_metadata));
+        assert_eq!(rewards[&accounts(3)].amount.0, 50);
+        assert_eq!(rewards[&accounts(3)].metadata, None);
+        assert!(contract.list_farmer_rewards_with_metadata(accounts(4)).is_empty());
+    }
+}
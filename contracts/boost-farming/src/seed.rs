@@ -24,16 +24,40 @@ pub struct Seed {
     pub slash_rate: u32,
     /// if min_lock_duration == 0, means forbid locking
     pub min_locking_duration_sec: DurationSec,
+    /// statistics of farmers counted in seed farmer index
+    pub farmer_count: u64,
+    #[serde(with = "u128_dec_format")]
+    pub total_free_amount: Balance,
+    /// include locked shadow
+    #[serde(with = "u128_dec_format")]
+    pub total_locked_amount: Balance,
+    /// include x locked shadow
+    #[serde(with = "u128_dec_format")]
+    pub total_x_locked_amount: Balance,
+    /// include locked shadow
+    #[serde(with = "u128_dec_format")]
+    pub total_shadow_amount: Balance,
+    /// outdated farms farmers can still claim from, with their claimable_until
+    #[serde(skip)]
+    pub claimable_outdated_farms: HashMap<FarmId, Timestamp>,
+    /// seed amount taken from the first deposit of an auto registered farmer
+    /// when sponsor pool is dry, 0 means not allowed
+    #[serde(with = "u128_dec_format")]
+    pub register_fee: Balance,
+    /// how to value a staked nft into seed amount, only for nft seeds
+    pub nft_valuation: Option<NftValuation>,
 }
 
 #[derive(BorshSerialize, BorshDeserialize)]
 pub enum VSeed {
+    V0(SeedV0),
     Current(Seed),
 }
 
 impl From<VSeed> for Seed {
     fn from(v: VSeed) -> Self {
         match v {
+            VSeed::V0(c) => c.into(),
             VSeed::Current(c) => c,
         }
     }
@@ -48,11 +72,19 @@ impl From<Seed> for VSeed {
 impl Seed {
 
     pub fn update(&mut self) {
+        let block_ts = env::block_timestamp();
+        self.claimable_outdated_farms.retain(|_, claimable_until| *claimable_until > block_ts);
         for (_, vfarm) in self.farms.iter_mut() {
             match vfarm {
                 VSeedFarm::V0(farm) => {
                     farm.update(self.total_seed_power);
                 }
+                VSeedFarm::V1(farm) => {
+                    // upgrade to current layout on the fly
+                    let mut farm: SeedFarm = farm.clone().into();
+                    farm.update(self.total_seed_power);
+                    *vfarm = VSeedFarm::Current(farm);
+                }
                 VSeedFarm::Current(farm) => {
                     farm.update(self.total_seed_power);
                 }
@@ -67,6 +99,9 @@ impl Seed {
                 VSeedFarm::V0(farm) => {
                     farm.claimed_reward += amount;
                 }
+                VSeedFarm::V1(farm) => {
+                    farm.claimed_reward += amount;
+                }
                 VSeedFarm::Current(farm) => {
                     farm.claimed_reward += amount;
                 }
@@ -91,8 +126,32 @@ impl Seed {
             min_deposit,
             slash_rate: default_slash_rate,
             min_locking_duration_sec,
+            farmer_count: 0,
+            total_free_amount: 0,
+            total_locked_amount: 0,
+            total_x_locked_amount: 0,
+            total_shadow_amount: 0,
+            claimable_outdated_farms: HashMap::new(),
+            register_fee: 0,
+            nft_valuation: None,
         }
     }
+
+    pub fn add_farmer_stats(&mut self, farmer_seed: &FarmerSeed) {
+        self.farmer_count += 1;
+        self.total_free_amount += farmer_seed.free_amount;
+        self.total_locked_amount += farmer_seed.locked_amount + farmer_seed.shadow_locked_amount;
+        self.total_x_locked_amount += farmer_seed.x_locked_amount + farmer_seed.shadow_x_locked_amount;
+        self.total_shadow_amount += farmer_seed.get_shadow_amount();
+    }
+
+    pub fn sub_farmer_stats(&mut self, farmer_seed: &FarmerSeed) {
+        self.farmer_count = self.farmer_count.saturating_sub(1);
+        self.total_free_amount = self.total_free_amount.saturating_sub(farmer_seed.free_amount);
+        self.total_locked_amount = self.total_locked_amount.saturating_sub(farmer_seed.locked_amount + farmer_seed.shadow_locked_amount);
+        self.total_x_locked_amount = self.total_x_locked_amount.saturating_sub(farmer_seed.x_locked_amount + farmer_seed.shadow_x_locked_amount);
+        self.total_shadow_amount = self.total_shadow_amount.saturating_sub(farmer_seed.get_shadow_amount());
+    }
 }
 
 impl Contract {
@@ -115,6 +174,7 @@ impl Contract {
     }
 
     pub fn internal_set_seed(&mut self, seed_id: &SeedId, seed: Seed) {
+        self.internal_record_farm_checkpoints(&seed);
         SEEDS
             .lock()
             .unwrap()
@@ -9,6 +9,22 @@ pub struct FarmTerms {
     pub start_at: u32,
     #[serde(with = "u128_dec_format")]
     pub daily_reward: Balance,
+    #[serde(flatten)]
+    pub options: FarmOptions,
+}
+
+/// Optional terms of a farm, farms converted from FarmTermsV0 start with the default,
+/// so adding an option here leaves the legacy conversion untouched.
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Default)]
+#[serde(crate = "near_sdk::serde")]
+#[serde(default)]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug))]
+pub struct FarmOptions {
+    /// account allowed to withdraw reward distributed while the seed is empty,
+    /// which goes to refund receivers if not set
+    pub beneficiary_id: Option<AccountId>,
+    /// pause reward distribution while the seed is empty instead of accumulating it for beneficiary
+    pub pause_when_empty: bool,
 }
 
 
@@ -17,6 +33,7 @@ pub struct FarmTerms {
 #[serde(crate = "near_sdk::serde")]
 pub enum VSeedFarm {
     V0(SeedFarmV0),
+    V1(SeedFarmV1),
     Current(SeedFarm),
 }
 
@@ -24,6 +41,7 @@ impl From<VSeedFarm> for SeedFarm {
     fn from(v: VSeedFarm) -> Self {
         match v {
             VSeedFarm::V0(c) => c.into(),
+            VSeedFarm::V1(c) => c.into(),
             VSeedFarm::Current(c) => c,
         }
     }
@@ -84,8 +102,21 @@ pub struct SeedFarm {
     #[serde(skip)]
     pub rps: BigDecimal,
 
+    /// when reward ran out, 0 if not ended yet or unknown for legacy farms
+    #[serde(with = "u64_dec_format")]
+    pub ended_at: Timestamp,
+
+    /// farmers can still claim from the farm before this time after it was outdated,
+    /// 0 if not outdated yet or unclaimed rewards already belong to beneficiary
+    #[serde(with = "u64_dec_format")]
+    pub claimable_until: Timestamp,
+
     #[borsh_skip]
     pub status: Option<FarmStatus>,
+
+    /// cached metadata of reward token, only filled in views
+    #[borsh_skip]
+    pub reward_token_metadata: Option<RewardTokenMetadata>,
 }
 
 impl SeedFarm {
@@ -100,7 +131,10 @@ impl SeedFarm {
             amount_of_beneficiary: 0,
             amount_of_withdrew_beneficiary: 0,
             rps: BigDecimal::from(0_u32),
+            ended_at: 0,
+            claimable_until: 0,
             status: Some(FarmStatus::Created),
+            reward_token_metadata: None,
         }
     }
 
@@ -135,9 +169,18 @@ impl SeedFarm {
 
         match self.status.as_ref().unwrap() {
             FarmStatus::Ended => {
+                if self.ended_at == 0 {
+                    // legacy farm, start counting from the first time we see it ended
+                    self.ended_at = block_ts;
+                }
                 self.distributed_at = block_ts;
             },
             FarmStatus::Running => {
+                if seed_power == 0 && self.terms.options.pause_when_empty {
+                    // reward stays undistributed until someone stakes
+                    self.distributed_at = block_ts;
+                    return;
+                }
                 let reward = std::cmp::min(
                     self.total_reward - self.distributed_reward,
                     u128_ratio(
@@ -152,8 +195,14 @@ impl SeedFarm {
                 } else {
                     self.amount_of_beneficiary += reward;
                 }
+                let prev_distributed_at = self.distributed_at;
                 self.distributed_at = block_ts;
                 self.internal_update_status(block_ts);
+                if self.has_ended() {
+                    // the moment the last reward was distributed
+                    let duration = u128_ratio(reward, u128::from(NANOS_PER_DAY), self.terms.daily_reward);
+                    self.ended_at = std::cmp::min(block_ts, prev_distributed_at + duration as u64);
+                }
             },
             _ => {},
         }
@@ -169,11 +218,62 @@ impl SeedFarm {
         (self.total_reward, self.terms.start_at)
     }
 
-    pub fn finalize(&mut self) {
+    pub fn get_remaining_reward(&self) -> Balance {
+        self.total_reward - self.distributed_reward
+    }
+
+    /// reward still held by the contract for this farm, including unclaimed and beneficiary parts
+    pub fn get_reward_liability(&self) -> Balance {
+        self.total_reward - self.claimed_reward - self.amount_of_withdrew_beneficiary
+    }
+
+    /// daily reward actually to be distributed, capped by remaining reward, zero before the farm starts
+    pub fn get_effective_daily_reward(&self) -> Balance {
+        if self.terms.start_at == 0 || to_nano(self.terms.start_at) > env::block_timestamp() {
+            return 0;
+        }
+        std::cmp::min(self.terms.daily_reward, self.get_remaining_reward())
+    }
+
+    /// estimated days (rounded up) until the farm ends, including days waiting for start
+    pub fn get_days_to_end(&self) -> u32 {
+        let remaining_reward = self.get_remaining_reward();
+        if remaining_reward == 0 || self.terms.daily_reward == 0 {
+            return 0;
+        }
+        let block_ts = env::block_timestamp();
+        let waiting_days = if to_nano(self.terms.start_at) > block_ts {
+            (to_nano(self.terms.start_at) - block_ts).div_ceil(NANOS_PER_DAY)
+        } else {
+            0
+        };
+        let distributing_days = remaining_reward.div_ceil(self.terms.daily_reward);
+        std::cmp::min(distributing_days + waiting_days as u128, u32::MAX as u128) as u32
+    }
+
+    /// whether the farm has ended for longer than grace_sec
+    pub fn is_expired(&self, grace_sec: DurationSec) -> bool {
+        self.has_ended() && self.ended_at > 0 && self.ended_at + to_nano(grace_sec) <= env::block_timestamp()
+    }
+
+    /// farmers keep claiming with the final rps for grace_sec, then the rest goes to beneficiary
+    pub fn outdate(&mut self, grace_sec: DurationSec) {
         require!(self.has_ended(), E405_FARM_NOT_ENDED);
+        self.claimable_until = env::block_timestamp() + to_nano(grace_sec);
+        if !self.is_claimable() {
+            self.finalize();
+        }
+    }
+
+    pub fn is_claimable(&self) -> bool {
+        env::block_timestamp() < self.claimable_until
+    }
+
+    pub fn finalize(&mut self) {
         // remaining unclaimed rewards belongs to beneficiary
         self.amount_of_beneficiary = 
             self.distributed_reward - self.claimed_reward;
+        self.claimable_until = 0;
     }
 
 }
@@ -188,7 +288,36 @@ impl Contract {
             .expect(E401_FARM_NOT_EXIST)
     }
 
+    /// beneficiary_id in terms of the farm, either active or outdated
+    pub fn internal_get_farm_beneficiary(&self, farm_id: &FarmId) -> Option<AccountId> {
+        let (seed_id, _) = parse_farm_id(farm_id);
+        self.internal_get_seed(&seed_id)
+            .and_then(|seed| seed.farms.get(farm_id).cloned())
+            .or_else(|| self.data().outdated_farms.get(farm_id))
+            .and_then(|vf| SeedFarm::from(vf).terms.options.beneficiary_id)
+    }
+
     pub fn internal_set_outdated_farm(&mut self, farm_id: &FarmId, farm: SeedFarm) {
         self.data_mut().outdated_farms.insert(farm_id, &farm.into());
     }
+
+    /// move an ended farm out of seed, farmers can still claim from it during the claim grace period
+    pub fn internal_outdate_farm(&mut self, seed: &mut Seed, farm_id: &FarmId) {
+        let mut outdated_farm: SeedFarm = seed.farms.remove(farm_id).expect(E401_FARM_NOT_EXIST).into();
+        outdated_farm.outdate(self.internal_config().outdated_farm_claim_grace_sec);
+        if outdated_farm.is_claimable() {
+            seed.claimable_outdated_farms.insert(farm_id.clone(), outdated_farm.claimable_until);
+        }
+        self.internal_set_outdated_farm(farm_id, outdated_farm);
+        self.data_mut().farm_checkpoints.remove(farm_id);
+        self.data_mut().farm_count -= 1;
+    }
+
+    pub fn internal_update_outdated_claimed(&mut self, claimed: &HashMap<FarmId, Balance>) {
+        for (farm_id, amount) in claimed {
+            let mut farm = self.internal_unwrap_outdated_farm(farm_id);
+            farm.claimed_reward += amount;
+            self.internal_set_outdated_farm(farm_id, farm);
+        }
+    }
 }
\ No newline at end of file
@@ -1,47 +1,178 @@
 use crate::*;
 
-use near_sdk::Gas;
+use near_sdk::{serde_json, Gas};
 
 pub const GAS_FOR_SHADOW_SEED_TRANSFER: Gas = Gas(20 * TGAS);
 pub const GAS_FOR_SHADOW_SEED_CALLBACK: Gas = Gas(10 * TGAS);
+pub const GAS_FOR_GET_SHADOW_RECORDS: Gas = Gas(10 * TGAS);
+pub const GAS_FOR_SYNC_SHADOW_CALLBACK: Gas = Gas(50 * TGAS);
 
 pub const SHADOW_V1_TOKEN_PREFIX: &str = "shadow_ref_v1-";
 
+/// Message parameters carried by on_cast_shadow, empty msg means Free.
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+enum ShadowMessage {
+    Free,
+    Lock { duration_sec: u32 },
+}
+
+/// Shadow record returned by ref-exchange get_shadow_records, keyed by pool_id.
+#[derive(Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ShadowRecordInfo {
+    pub shadow_in_farm: U128,
+}
+
+/// How a provider's shadow_id is translated into a seed_id.
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
+pub enum ShadowSeedRule {
+    /// "<shadow_prefix><pool_id>" maps to MFT seed "<token_id>@<pool_id>"
+    MftPool { token_id: AccountId },
+    /// "<shadow_prefix><token_id>" maps to FT seed "<token_id>"
+    FtToken,
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+#[cfg_attr(not(target_arch = "wasm32"), derive(Debug, PartialEq))]
+pub struct ShadowProvider {
+    pub shadow_prefix: String,
+    pub rule: ShadowSeedRule,
+    /// only these seeds can be shadow staked through this provider
+    pub allowed_seeds: Vec<SeedId>,
+}
+
+impl ShadowProvider {
+    pub fn shadow_id_to_seed_id(&self, shadow_id: &str) -> SeedId {
+        let suffix = shadow_id.strip_prefix(&self.shadow_prefix).expect(E701_INVALID_SHADOW_ID);
+        require!(!suffix.is_empty(), E701_INVALID_SHADOW_ID);
+        let seed_id = match &self.rule {
+            ShadowSeedRule::MftPool { token_id } => {
+                let pool_id = suffix.parse::<u64>().expect(E701_INVALID_SHADOW_ID);
+                format!("{}{}{}", token_id, SEED_TAG, pool_id)
+            }
+            ShadowSeedRule::FtToken => {
+                suffix.parse::<AccountId>().expect(E701_INVALID_SHADOW_ID).to_string()
+            }
+        };
+        require!(self.allowed_seeds.contains(&seed_id), E702_SHADOW_SEED_NOT_ALLOWED);
+        seed_id
+    }
+}
+
 impl Contract {
-    pub fn shadow_id_to_seed_id(&self, shadow_id: &String) -> SeedId {
-        if shadow_id.to_string().starts_with(SHADOW_V1_TOKEN_PREFIX) {
-            let pool_id = shadow_id.split("-").collect::<Vec<&str>>()[1].parse::<u64>().expect("Invalid shadow_id");
+    pub fn is_shadow_provider(&self, provider_id: &AccountId) -> bool {
+        provider_id == &self.data().ref_exchange_id || self.data().shadow_providers.get(provider_id).is_some()
+    }
+
+    /// registered providers take precedence, ref_exchange falls back to the built-in v1 rule
+    pub fn shadow_id_to_seed_id(&self, provider_id: &AccountId, shadow_id: &str) -> SeedId {
+        if let Some(provider) = self.data().shadow_providers.get(provider_id) {
+            provider.shadow_id_to_seed_id(shadow_id)
+        } else if provider_id == &self.data().ref_exchange_id && shadow_id.starts_with(SHADOW_V1_TOKEN_PREFIX) {
+            let pool_id = shadow_id.split_once('-').and_then(|(_, pool_id)| pool_id.parse::<u64>().ok());
+            require!(pool_id.is_some(), E502_INVALID_SHADOW_ID);
+            let pool_id = pool_id.unwrap();
             format!("{}@{}", self.data().ref_exchange_id, pool_id)
         } else {
-            unimplemented!()
+            env::panic_str(E701_INVALID_SHADOW_ID)
+        }
+    }
+
+    /// the index entry is counted into the farmer
+    pub fn internal_update_shadow_staker(&mut self, farmer: &mut Farmer, seed_id: &SeedId, farmer_seed: &FarmerSeed) {
+        let prev_storage = env::storage_usage();
+        let key = (farmer.farmer_id.clone(), seed_id.clone());
+        if farmer_seed.get_shadow_amount() > 0 {
+            self.data_mut().shadow_stakers.insert(&key);
+        } else {
+            self.data_mut().shadow_stakers.remove(&key);
+        }
+        farmer.update_storage_usage(prev_storage);
+    }
+
+    fn internal_remove_shadow(&mut self, farmer_id: &AccountId, provider_id: &AccountId, seed_id: &SeedId, withdraw_amount: Balance) {
+        let mut farmer = self.internal_unwrap_farmer(farmer_id);
+        self.internal_do_farmer_claim(&mut farmer, seed_id);
+        self.sync_booster_policy(&mut farmer);
+        let mut seed = self.internal_unwrap_seed(seed_id);
+
+        let mut farmer_seed = farmer.get_seed_unwrap(seed_id);
+
+        let prev = farmer_seed.get_seed_power();
+
+        let is_ref_exchange = provider_id == &self.data().ref_exchange_id;
+        farmer_seed.remove_provider_shadow(provider_id, withdraw_amount, is_ref_exchange);
+        farmer_seed.withdraw_shadow(withdraw_amount);
+        farmer.assert_delegation_covered(seed_id, &farmer_seed);
+
+        seed.total_seed_amount -= withdraw_amount;
+        seed.total_seed_power = seed.total_seed_power - prev + farmer_seed.get_seed_power();
+
+        self.internal_update_shadow_staker(&mut farmer, seed_id, &farmer_seed);
+        self.internal_set_farmer_seed(&mut farmer, &mut seed, farmer_seed);
+
+        self.update_impacted_seeds(&mut farmer, seed_id);
+
+        // called by provider and owner, storage of the farmer is not checked
+        self.internal_save_farmer(farmer_id, farmer);
+        self.internal_set_seed(seed_id, seed);
+
+        Event::ShadowSeedWithdraw {
+            farmer_id,
+            seed_id,
+            withdraw_amount: &U128(withdraw_amount),
         }
+        .emit();
     }
 }
 
 #[near_bindgen]
 impl Contract {
     pub fn on_cast_shadow(&mut self, account_id: AccountId, shadow_id: String, amount: U128, msg: String) {
-        require!(self.data().ref_exchange_id == env::predecessor_account_id(), E002_NOT_ALLOWED);
+        let provider_id = env::predecessor_account_id();
+        require!(self.is_shadow_provider(&provider_id), E002_NOT_ALLOWED);
         require!(self.data().state == RunningState::Running, E004_CONTRACT_PAUSED);
-        require!(msg.is_empty(), E501_UNEXPECTED_MSG);
+        let message = if msg.is_empty() {
+            ShadowMessage::Free
+        } else {
+            serde_json::from_str::<ShadowMessage>(&msg).expect(E500_INVALID_MSG)
+        };
 
+        let seed_id = self.shadow_id_to_seed_id(&provider_id, &shadow_id);
+        // shadow seed stays in provider, so only sponsor pool can pay for registration
+        self.internal_auto_register_farmer(&account_id, &seed_id, amount.0, false);
         let mut farmer = self.internal_unwrap_farmer(&account_id);
-        let seed_id = self.shadow_id_to_seed_id(&shadow_id);
         self.internal_do_farmer_claim(&mut farmer, &seed_id);
         self.sync_booster_policy(&mut farmer);
         let mut seed = self.internal_unwrap_seed(&seed_id);
         require!(amount.0 >= seed.min_deposit, E307_BELOW_MIN_DEPOSIT);
 
         let mut farmer_seed = farmer.get_seed_unwrap(&seed_id);
-        let increased_seed_power = farmer_seed.add_shadow(amount.0);
-        farmer.set_seed(&seed_id, farmer_seed);
+        let (increased_seed_power, duration) = match message {
+            ShadowMessage::Free => (farmer_seed.add_shadow(amount.0), 0),
+            ShadowMessage::Lock { duration_sec } => {
+                require!(seed.min_locking_duration_sec > 0, E300_FORBID_LOCKING);
+                require!(duration_sec >= seed.min_locking_duration_sec, E201_INVALID_DURATION);
+                let config = self.internal_config();
+                require!(duration_sec <= config.maximum_locking_duration_sec, E201_INVALID_DURATION);
+                (farmer_seed.add_shadow_lock(amount.0, duration_sec, &config), duration_sec)
+            }
+        };
+        farmer_seed.add_provider_shadow(&provider_id, amount.0);
+        self.internal_update_shadow_staker(&mut farmer, &seed_id, &farmer_seed);
+        self.internal_set_farmer_seed(&mut farmer, &mut seed, farmer_seed);
 
         seed.total_seed_amount += amount.0;
         seed.total_seed_power += increased_seed_power;
 
         self.update_impacted_seeds(&mut farmer, &seed_id);
 
-        self.internal_set_farmer(&account_id, farmer);
+        // shadow stays in provider, failing here would leave provider and farm out of sync
+        self.internal_save_farmer(&account_id, farmer);
         self.internal_set_seed(&seed_id, seed);
 
         Event::ShadowSeedDeposit {
@@ -49,50 +180,171 @@ impl Contract {
             seed_id: &seed_id,
             deposit_amount: &U128(amount.0),
             increased_power: &U128(increased_seed_power),
-            duration: 0,
+            duration,
         }
         .emit();
     }
 
     pub fn on_remove_shadow(&mut self, account_id: AccountId, shadow_id: String, amount: U128, msg: String) {
-        require!(self.data().ref_exchange_id == env::predecessor_account_id(), E002_NOT_ALLOWED);
+        let provider_id = env::predecessor_account_id();
+        require!(self.is_shadow_provider(&provider_id), E002_NOT_ALLOWED);
         require!(self.data().state == RunningState::Running, E004_CONTRACT_PAUSED);
         require!(msg.is_empty(), E501_UNEXPECTED_MSG);
 
         let withdraw_amount: Balance = amount.into();
         require!(withdraw_amount > 0, "amount must greater than 0!");
 
-        let mut farmer = self.internal_unwrap_farmer(&account_id);
-        let seed_id = self.shadow_id_to_seed_id(&shadow_id);
+        let seed_id = self.shadow_id_to_seed_id(&provider_id, &shadow_id);
+        self.internal_remove_shadow(&account_id, &provider_id, &seed_id, withdraw_amount);
+    }
+
+    /// Remove all shadow cast by a provider which has been removed from the registry,
+    /// so that the farmer can still leave the seed. Can be called by the farmer or owner.
+    #[payable]
+    pub fn remove_orphan_shadow(&mut self, farmer_id: AccountId, provider_id: AccountId, seed_id: SeedId) {
+        assert_one_yocto();
+        require!(
+            env::predecessor_account_id() == farmer_id || env::predecessor_account_id() == self.data().owner_id,
+            E002_NOT_ALLOWED
+        );
+        require!(self.data().state == RunningState::Running, E004_CONTRACT_PAUSED);
+        require!(!self.is_shadow_provider(&provider_id), E705_SHADOW_PROVIDER_STILL_ACTIVE);
+
+        let amount = self
+            .internal_unwrap_farmer(&farmer_id)
+            .get_seed(&seed_id)
+            .expect(E301_SEED_NOT_EXIST)
+            .get_provider_shadow(&provider_id, false);
+        require!(amount > 0, E704_SHADOW_NOT_CAST_BY_PROVIDER);
+        self.internal_remove_shadow(&farmer_id, &provider_id, &seed_id, amount);
+    }
+
+    /// Pull farmer's shadow amount of a ref-exchange pool seed from ref_exchange_id,
+    /// and overwrite the shadow cast by ref-exchange with it. Only can be called by owner.
+    #[payable]
+    pub fn sync_shadow_amount(&mut self, farmer_id: AccountId, seed_id: SeedId) -> Promise {
+        assert_one_yocto();
+        self.assert_owner();
+        let (token, pool_id) = parse_seed_id(&seed_id);
+        require!(!is_mt_seed_id(&seed_id) && !is_nft_seed_id(&seed_id) && token == self.data().ref_exchange_id && pool_id.is_some(), E308_INVALID_SEED_ID);
+        self.internal_unwrap_farmer(&farmer_id);
+        self.internal_unwrap_seed(&seed_id);
+
+        ext_ref_exchange::get_shadow_records(
+            farmer_id.clone(),
+            self.data().ref_exchange_id.clone(),
+            0,
+            GAS_FOR_GET_SHADOW_RECORDS,
+        )
+        .then(ext_self::callback_sync_shadow_amount(
+            farmer_id,
+            seed_id,
+            env::current_account_id(),
+            0,
+            GAS_FOR_SYNC_SHADOW_CALLBACK,
+        ))
+    }
+
+    #[private]
+    pub fn callback_sync_shadow_amount(&mut self, farmer_id: AccountId, seed_id: SeedId) {
+        require!(
+            env::promise_results_count() == 1,
+            E001_PROMISE_RESULT_COUNT_INVALID
+        );
+        let (_, pool_id) = parse_seed_id(&seed_id);
+        let shadow_in_farm = match env::promise_result(0) {
+            PromiseResult::NotReady => unreachable!(),
+            PromiseResult::Failed => env::panic_str(E703_SHADOW_SYNC_FAILED),
+            PromiseResult::Successful(value) => {
+                let records = serde_json::from_slice::<HashMap<String, ShadowRecordInfo>>(&value)
+                    .expect(E703_SHADOW_SYNC_FAILED);
+                records.get(&pool_id.unwrap()).map(|r| r.shadow_in_farm.0).unwrap_or(0)
+            }
+        };
+
+        let mut farmer = self.internal_unwrap_farmer(&farmer_id);
         self.internal_do_farmer_claim(&mut farmer, &seed_id);
         self.sync_booster_policy(&mut farmer);
         let mut seed = self.internal_unwrap_seed(&seed_id);
 
         let mut farmer_seed = farmer.get_seed_unwrap(&seed_id);
+        let prev_power = farmer_seed.get_seed_power();
 
-        let prev = farmer_seed.get_seed_power();
+        // shadow cast by other providers is left untouched
+        let ref_exchange_id = self.data().ref_exchange_id.clone();
+        let prev_amount = farmer_seed.reset_provider_shadow(&ref_exchange_id, shadow_in_farm, true);
 
-        farmer_seed.withdraw_shadow(withdraw_amount);
+        seed.total_seed_amount = seed.total_seed_amount - prev_amount + shadow_in_farm;
+        seed.total_seed_power = seed.total_seed_power - prev_power + farmer_seed.get_seed_power();
 
-        seed.total_seed_amount -= withdraw_amount;
-        seed.total_seed_power = seed.total_seed_power - prev + farmer_seed.get_seed_power();
-
-        if farmer_seed.is_empty() {
-            farmer.remove_seed(&seed_id);
-        } else {
-            farmer.set_seed(&seed_id, farmer_seed);
-        }
+        self.internal_update_shadow_staker(&mut farmer, &seed_id, &farmer_seed);
+        self.internal_set_farmer_seed(&mut farmer, &mut seed, farmer_seed);
 
         self.update_impacted_seeds(&mut farmer, &seed_id);
 
-        self.internal_set_farmer(&account_id, farmer);
+        // callback repairing shadow state, storage of the farmer is not checked
+        self.internal_save_farmer(&farmer_id, farmer);
         self.internal_set_seed(&seed_id, seed);
 
-        Event::ShadowSeedWithdraw {
-            farmer_id: &account_id,
+        Event::ShadowSeedSync {
+            farmer_id: &farmer_id,
             seed_id: &seed_id,
-            withdraw_amount: &U128(withdraw_amount),
+            prev_amount: &U128(prev_amount),
+            current_amount: &U128(shadow_in_farm),
         }
         .emit();
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod shadow_tests {
+    use super::*;
+    use crate::unit_env::*;
+    use near_sdk::test_utils::accounts;
+
+    #[test]
+    fn sync_shadow_of_ref_exchange() {
+        let ref_exchange: AccountId = "ref_exchange".parse().unwrap();
+        let lending: AccountId = "lending".parse().unwrap();
+        let seed_id = "ref_exchange@0".to_string();
+        let (mut context, mut contract) = setup_contract();
+
+        testing_env!(context.predecessor_account_id(accounts(0)).attached_deposit(1).build());
+        contract.create_seed(seed_id.clone(), 18, Some(0.into()), None);
+        contract.set_shadow_provider(lending.clone(), ShadowProvider {
+            shadow_prefix: "shadow_lending-".to_string(),
+            rule: ShadowSeedRule::MftPool { token_id: ref_exchange.clone() },
+            allowed_seeds: vec![seed_id.clone()],
+        });
+
+        testing_env!(context.predecessor_account_id(ref_exchange.clone()).attached_deposit(0).build());
+        contract.on_cast_shadow(accounts(1), "shadow_ref_v1-0".to_string(), 100.into(), "".to_string());
+        testing_env!(context.predecessor_account_id(lending.clone()).build());
+        contract.on_cast_shadow(accounts(1), "shadow_lending-0".to_string(), 50.into(), "".to_string());
+
+        // shadow stakes recorded before the index are picked up by backfill
+        contract.data_mut().shadow_stakers.clear();
+        assert!(contract.list_shadow_stakes(None, None).is_empty());
+        testing_env!(context.predecessor_account_id(accounts(0)).attached_deposit(1).build());
+        contract.backfill_farmer_index(vec![accounts(1)]);
+        assert_eq!(contract.list_shadow_stakes(None, None).len(), 1);
+
+        // only the part cast by ref-exchange follows its records
+        let records = serde_json::json!({ "0": { "shadow_in_farm": "80" } });
+        set_promise_result(&mut context, PromiseResult::Successful(records.to_string().into_bytes()));
+        contract.callback_sync_shadow_amount(accounts(1), seed_id.clone());
+        let farmer_seed = contract.get_farmer_seed(accounts(1), seed_id.clone()).unwrap();
+        assert_eq!(farmer_seed.shadow_amount, 130);
+        assert_eq!(farmer_seed.get_provider_shadow(&ref_exchange, true), 80);
+        assert_eq!(farmer_seed.get_provider_shadow(&lending, false), 50);
+        assert_eq!(contract.get_seed(seed_id).unwrap().total_seed_amount, 130);
+    }
+
+    #[test]
+    #[should_panic(expected = "E502: invalid shadow_id")]
+    fn cast_malformed_ref_exchange_shadow() {
+        let (mut context, mut contract) = setup_contract();
+        testing_env!(context.predecessor_account_id("ref_exchange".parse().unwrap()).attached_deposit(0).build());
+        contract.on_cast_shadow(accounts(1), "shadow_ref_v1-x".to_string(), 100.into(), "".to_string());
+    }
+}
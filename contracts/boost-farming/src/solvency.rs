@@ -0,0 +1,174 @@
+use crate::*;
+use near_contract_standards::fungible_token::core_impl::ext_fungible_token;
+
+impl Contract {
+    /// number of entries walked by liability collection,
+    /// in order of seeds, outdated_farms, seeds_slashed, seeds_register_fee, seeds_lostfound, farmer_ids and rewards_lostfound
+    fn internal_liability_entry_count(&self) -> u64 {
+        self.data().seeds.len()
+            + self.data().outdated_farms.len()
+            + self.data().seeds_slashed.len()
+            + self.data().seeds_register_fee.len()
+            + self.data().seeds_lostfound.len()
+            + self.data().farmer_ids.len()
+            + self.data().rewards_lostfound.len()
+    }
+
+    /// add liabilities of the entry at given index of the walking list, keyed by token key
+    /// which is the seed_id of a seed token or the account of a reward token
+    fn internal_add_liability_entry(&self, mut index: u64, liabilities: &mut HashMap<String, Balance>) {
+        let mut add = |token_key: String, amount: Balance| {
+            if amount > 0 {
+                *liabilities.entry(token_key).or_insert(0) += amount;
+            }
+        };
+
+        let seeds = self.data().seeds.values_as_vector();
+        if index < seeds.len() {
+            let seed: Seed = seeds.get(index).unwrap().into();
+            // shadow seeds stay in ref-exchange and nfts are not fungible
+            if !is_nft_seed_id(&seed.seed_id) {
+                add(seed.seed_id.clone(), seed.total_seed_amount - seed.total_shadow_amount);
+            }
+            for farm in seed.farms.values() {
+                let farm: SeedFarm = farm.clone().into();
+                add(farm.terms.reward_token.to_string(), farm.get_reward_liability());
+            }
+            return;
+        }
+        index -= seeds.len();
+
+        let outdated_farms = self.data().outdated_farms.values_as_vector();
+        if index < outdated_farms.len() {
+            let farm: SeedFarm = outdated_farms.get(index).unwrap().into();
+            add(farm.terms.reward_token.to_string(), farm.get_reward_liability());
+            return;
+        }
+        index -= outdated_farms.len();
+
+        for seeds_map in [&self.data().seeds_slashed, &self.data().seeds_register_fee, &self.data().seeds_lostfound] {
+            if index < seeds_map.len() {
+                let seed_id = seeds_map.keys_as_vector().get(index).unwrap();
+                add(seed_id.clone(), seeds_map.get(&seed_id).unwrap());
+                return;
+            }
+            index -= seeds_map.len();
+        }
+
+        let farmer_ids = self.data().farmer_ids.as_vector();
+        if index < farmer_ids.len() {
+            let farmer = self.internal_unwrap_farmer(&farmer_ids.get(index).unwrap());
+            for (token_id, amount) in farmer.rewards {
+                add(token_id.to_string(), amount);
+            }
+            return;
+        }
+        index -= farmer_ids.len();
+
+        let rewards_lostfound = self.data().rewards_lostfound.keys_as_vector();
+        if index < rewards_lostfound.len() {
+            let key = rewards_lostfound.get(index).unwrap();
+            add(key.1.to_string(), self.data().rewards_lostfound.get(&key).unwrap());
+        }
+    }
+
+    pub(crate) fn internal_collect_liabilities(&self, from_index: u64, limit: u64) -> HashMap<String, Balance> {
+        let mut liabilities = HashMap::new();
+        let total = self.internal_liability_entry_count();
+        for index in from_index..std::cmp::min(from_index + limit, total) {
+            self.internal_add_liability_entry(index, &mut liabilities);
+        }
+        liabilities
+    }
+
+    fn internal_query_token_balance(&self, token_key: &String) -> Promise {
+        let (token, token_id) = parse_seed_id(token_key);
+        match token_id {
+            Some(token_id) if is_mt_seed_id(token_key) => ext_multi_token::mt_balance_of(
+                env::current_account_id(),
+                token_id,
+                token,
+                0,
+                GAS_FOR_BALANCE_OF,
+            ),
+            Some(token_id) => ext_multi_fungible_token::mft_balance_of(
+                wrap_mft_token_id(&token_id),
+                env::current_account_id(),
+                token,
+                0,
+                GAS_FOR_BALANCE_OF,
+            ),
+            None => ext_fungible_token::ft_balance_of(
+                env::current_account_id(),
+                token,
+                0,
+                GAS_FOR_BALANCE_OF,
+            ),
+        }
+    }
+}
+
+#[near_bindgen]
+impl Contract {
+    /// Expected token liabilities of this contract, keyed by seed_id for seed tokens
+    /// and by token account for reward tokens.
+    /// Entries of seeds, outdated_farms, seeds_slashed, seeds_register_fee, seeds_lostfound, farmers and rewards_lostfound
+    /// are walked as one list, sum up all pages to get the totals.
+    pub fn get_liabilities(&self, from_index: Option<u64>, limit: Option<u64>) -> HashMap<String, U128> {
+        let from_index = from_index.unwrap_or(0);
+        let limit = limit.unwrap_or(self.internal_liability_entry_count());
+        self.internal_collect_liabilities(from_index, limit)
+            .into_iter()
+            .map(|(token_key, amount)| (token_key, amount.into()))
+            .collect()
+    }
+
+    /// owner compares liabilities of given tokens with their actual balances,
+    /// the liabilities are totals summed up from all pages of get_liabilities,
+    /// since walking the whole list in one call runs out of gas on a large contract.
+    /// At most MAX_NUM_SOLVENCY_CHECKS tokens per call, split more tokens into several calls.
+    /// emits SolvencyCheck for each token, flagged insolvent only when balance is less than the liability
+    #[payable]
+    pub fn check_solvency(&mut self, liabilities: HashMap<String, U128>) {
+        assert_one_yocto();
+        self.assert_owner();
+        require!(liabilities.len() <= MAX_NUM_SOLVENCY_CHECKS, E010_EXCEED_SOLVENCY_CHECK_NUM);
+
+        for (token_key, expected) in liabilities {
+            require!(!is_nft_seed_id(&token_key), E606_NFT_SEED_OPERATION);
+            self.internal_query_token_balance(&token_key)
+                .then(ext_self::callback_check_solvency(
+                    token_key,
+                    expected,
+                    env::current_account_id(),
+                    0,
+                    GAS_FOR_RESOLVE_BALANCE_OF,
+                ));
+        }
+    }
+
+    /// return true if actual balance covers the expected liability
+    #[private]
+    pub fn callback_check_solvency(&mut self, token_key: String, expected: U128) -> bool {
+        require!(
+            env::promise_results_count() == 1,
+            E001_PROMISE_RESULT_COUNT_INVALID
+        );
+        let actual = match env::promise_result(0) {
+            PromiseResult::NotReady => unreachable!(),
+            PromiseResult::Failed => None,
+            PromiseResult::Successful(value) => near_sdk::serde_json::from_slice::<U128>(&value).ok(),
+        };
+        let insolvent = actual.map(|actual| actual.0 < expected.0).unwrap_or(false);
+        Event::SolvencyCheck {
+            token_key: &token_key,
+            expected: &expected,
+            actual: actual.as_ref(),
+            surplus: &U128(actual.map(|actual| actual.0.saturating_sub(expected.0)).unwrap_or(0)),
+            insolvent,
+            failed: actual.is_none(),
+        }
+        .emit();
+        actual.is_some() && !insolvent
+    }
+}
@@ -11,10 +11,112 @@ use near_contract_standards::storage_management::{
 
 
 
+impl Contract {
+    pub fn internal_register_farmer(&mut self, farmer_id: &AccountId, sponsor_id: &AccountId, storage_deposit: Balance, sponsored_by_pool: bool) {
+        let prev_storage = env::storage_usage();
+        let mut farmer = Farmer::new(farmer_id, sponsor_id);
+        farmer.storage_deposit = storage_deposit;
+        farmer.sponsored_by_pool = sponsored_by_pool;
+        self.data_mut().farmer_ids.insert(farmer_id);
+        self.data_mut().farmer_count += 1;
+        self.internal_update_sponsored_farmer(sponsor_id, farmer_id, true);
+        // farmer index entry is counted into the farmer
+        farmer.update_storage_usage(prev_storage);
+        self.internal_set_farmer(farmer_id, farmer);
+    }
+
+    /// remove an empty farmer, registration fee goes back to sponsor, extra deposit goes back to farmer
+    pub fn internal_unregister_farmer(&mut self, farmer: Farmer) {
+        require!(
+            farmer.rewards.is_empty(),
+            E103_STILL_HAS_REWARD
+        );
+        require!(
+            farmer.seeds.is_empty() && farmer.vseeds.is_empty(),
+            E104_STILL_HAS_SEED
+        );
+        require!(
+            farmer.booster_delegations_out.is_empty() && farmer.booster_delegations_in.is_empty(),
+            E105_STILL_HAS_DELEGATION
+        );
+
+        let farmer_id = farmer.farmer_id.clone();
+        self.data_mut().farmers.remove(&farmer_id);
+        self.data_mut().farmer_ids.remove(&farmer_id);
+        self.data_mut().farmer_count -= 1;
+        self.internal_update_sponsored_farmer(&farmer.sponsor_id, &farmer_id, false);
+
+        if farmer.sponsor_id == env::current_account_id() {
+            // fee paid in seed token was never deposited as NEAR, nothing to give back
+            if farmer.sponsored_by_pool {
+                self.data_mut().farmer_sponsor_pool += STORAGE_BALANCE_MIN_BOUND;
+            }
+        } else {
+            Promise::new(farmer.sponsor_id.clone()).transfer(STORAGE_BALANCE_MIN_BOUND);
+        }
+        let extra_deposit = farmer.storage_deposit.saturating_sub(farmer.get_registration_deposit());
+        if extra_deposit > 0 {
+            Promise::new(farmer_id).transfer(extra_deposit);
+        }
+    }
+
+    /// register farmer on its first seed deposit if auto registration is on, the contract becomes its sponsor.
+    /// Registration fee is paid by sponsor pool, or by register_fee of the seed taken from seed_amount
+    /// if sponsor pool is dry and pay_by_seed is allowed.
+    /// Return the seed amount left to stake.
+    pub fn internal_auto_register_farmer(
+        &mut self,
+        farmer_id: &AccountId,
+        seed_id: &SeedId,
+        seed_amount: Balance,
+        pay_by_seed: bool,
+    ) -> Balance {
+        if self.data().farmers.contains_key(farmer_id) || !self.internal_config().auto_register_farmer {
+            return seed_amount;
+        }
+
+        let register_fee = if self.data().farmer_sponsor_pool >= STORAGE_BALANCE_MIN_BOUND {
+            self.data_mut().farmer_sponsor_pool -= STORAGE_BALANCE_MIN_BOUND;
+            0
+        } else {
+            let register_fee = if pay_by_seed { self.internal_unwrap_seed(seed_id).register_fee } else { 0 };
+            require!(register_fee > 0 && seed_amount > register_fee, E100_ACC_NOT_REGISTERED);
+            let collected = self.data().seeds_register_fee.get(seed_id).unwrap_or(0);
+            self.data_mut().seeds_register_fee.insert(seed_id, &(collected + register_fee));
+            register_fee
+        };
+        // only the fee paid by sponsor pool is backed by NEAR
+        let storage_deposit = if register_fee == 0 { STORAGE_BALANCE_MIN_BOUND } else { 0 };
+        self.internal_register_farmer(farmer_id, &env::current_account_id(), storage_deposit, register_fee == 0);
+
+        Event::FarmerAutoRegister {
+            farmer_id,
+            seed_id,
+            register_fee: &U128(register_fee),
+        }
+        .emit();
+
+        seed_amount - register_fee
+    }
+}
+
+#[near_bindgen]
+impl Contract {
+    /// sponsor unregisters an empty farmer it paid for to get the registration fee back
+    #[payable]
+    pub fn reclaim_storage(&mut self, farmer_id: AccountId) {
+        assert_one_yocto();
+        require!(self.data().state == RunningState::Running, E004_CONTRACT_PAUSED);
+
+        let farmer = self.internal_unwrap_farmer(&farmer_id);
+        require!(farmer.sponsor_id == env::predecessor_account_id(), E002_NOT_ALLOWED);
+        self.internal_unregister_farmer(farmer);
+    }
+}
+
 /// Implements users storage management for the pool.
 #[near_bindgen]
 impl StorageManagement for Contract {
-    #[allow(unused_variables)]
     #[payable]
     fn storage_deposit(
         &mut self,
@@ -26,19 +128,25 @@ impl StorageManagement for Contract {
         let amount = env::attached_deposit();
         let account_id = account_id.unwrap_or_else(|| env::predecessor_account_id());
         let sponsor_id = env::predecessor_account_id();
-        let already_registered = self.data().farmers.contains_key(&account_id);
-        if amount < STORAGE_BALANCE_MIN_BOUND && !already_registered {
-            env::panic_str(E102_INSUFFICIENT_STORAGE);
-        }
+        // extra deposit goes back to the farmer on unregister, so only the farmer itself can put it in
+        let registration_only = registration_only.unwrap_or(false) || account_id != sponsor_id;
 
-        if already_registered {
-            if amount > 0 {
-                Promise::new(env::predecessor_account_id()).transfer(amount);
+        if let Some(mut farmer) = self.internal_get_farmer(&account_id) {
+            if registration_only {
+                if amount > 0 {
+                    Promise::new(env::predecessor_account_id()).transfer(amount);
+                }
+            } else {
+                farmer.storage_deposit += amount;
+                self.internal_set_farmer(&account_id, farmer);
+            }
+        } else {
+            if amount < STORAGE_BALANCE_MIN_BOUND {
+                env::panic_str(E102_INSUFFICIENT_STORAGE);
             }
-        } else {            
-            self.data_mut().farmers.insert(&account_id, &Farmer::new(&account_id, &sponsor_id).into());
-            self.data_mut().farmer_count += 1;
-            let refund = amount - STORAGE_BALANCE_MIN_BOUND;
+            let storage_deposit = if registration_only { STORAGE_BALANCE_MIN_BOUND } else { amount };
+            self.internal_register_farmer(&account_id, &sponsor_id, storage_deposit, false);
+            let refund = amount - storage_deposit;
             if refund > 0 {
                 Promise::new(env::predecessor_account_id()).transfer(refund);
             }
@@ -46,12 +154,23 @@ impl StorageManagement for Contract {
         self.storage_balance_of(account_id).unwrap()
     }
 
-    #[allow(unused_variables)]
+    /// withdraw storage deposit above the registration fee and the storage in use
     #[payable]
     fn storage_withdraw(&mut self, amount: Option<U128>) -> StorageBalance {
         assert_one_yocto();
         require!(self.data().state == RunningState::Running, E004_CONTRACT_PAUSED);
-        env::panic_str(E006_NOT_IMPLEMENTED);
+
+        let account_id = env::predecessor_account_id();
+        let mut farmer = self.internal_unwrap_farmer(&account_id);
+        let available = farmer.get_available_storage_deposit();
+        let amount = amount.map(|v| v.0).unwrap_or(available);
+        require!(amount <= available, E101_INSUFFICIENT_BALANCE);
+        if amount > 0 {
+            farmer.storage_deposit -= amount;
+            self.internal_set_farmer(&account_id, farmer);
+            Promise::new(account_id.clone()).transfer(amount);
+        }
+        self.storage_balance_of(account_id).unwrap()
     }
 
     #[allow(unused_variables)]
@@ -65,19 +184,7 @@ impl StorageManagement for Contract {
 
         let account_id = env::predecessor_account_id();
         if let Some(farmer) = self.internal_get_farmer(&account_id) {
-            
-            require!(
-                farmer.rewards.is_empty(),
-                E103_STILL_HAS_REWARD
-            );
-            require!(
-                farmer.seeds.is_empty() && farmer.vseeds.is_empty(),
-                E104_STILL_HAS_SEED
-            );
-
-            self.data_mut().farmers.remove(&account_id);
-            self.data_mut().farmer_count -= 1;
-            Promise::new(farmer.sponsor_id.clone()).transfer(STORAGE_BALANCE_MIN_BOUND);
+            self.internal_unregister_farmer(farmer);
             true
         } else {
             false
@@ -87,18 +194,54 @@ impl StorageManagement for Contract {
     fn storage_balance_bounds(&self) -> StorageBalanceBounds {
         StorageBalanceBounds {
             min: U128(STORAGE_BALANCE_MIN_BOUND),
-            max: Some(U128(STORAGE_BALANCE_MIN_BOUND)),
+            max: None,
         }
     }
 
     fn storage_balance_of(&self, account_id: AccountId) -> Option<StorageBalance> {
-        if self.data().farmers.contains_key(&account_id) {
-            Some(StorageBalance {
-                total: U128(STORAGE_BALANCE_MIN_BOUND),
-                available: U128(0),
-            })
-        }else{
-            None
-        }
+        self.internal_get_farmer(&account_id).map(|farmer| StorageBalance {
+            total: U128(farmer.storage_deposit),
+            available: U128(farmer.get_available_storage_deposit()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod storage_tests {
+    use super::*;
+    use crate::unit_env::*;
+    use near_sdk::test_utils::accounts;
+
+    #[test]
+    fn register_fee_paid_by_seed() {
+        let seed_id = "seed_paid@0".to_string();
+        let (mut context, mut contract) = setup_contract();
+        testing_env!(context.predecessor_account_id(accounts(0)).attached_deposit(1).build());
+        contract.create_seed(seed_id.clone(), 18, None, None);
+        contract.modify_auto_register_farmer(true);
+        contract.modify_seed_register_fee(seed_id.clone(), 10.into());
+
+        assert_eq!(contract.internal_auto_register_farmer(&accounts(2), &seed_id, 100, true), 90);
+        assert_eq!(contract.list_seeds_register_fee(None, None)[&seed_id].0, 10);
+        // no NEAR backs the registration fee
+        let storage_balance = contract.storage_balance_of(accounts(2)).unwrap();
+        assert_eq!(storage_balance.total.0, 0);
+        assert_eq!(storage_balance.available.0, 0);
+
+        // farmer tops up by itself, all of it can be withdrawn
+        testing_env!(context.predecessor_account_id(accounts(2)).attached_deposit(d(1, 23)).build());
+        contract.storage_deposit(None, None);
+        let storage_balance = contract.storage_balance_of(accounts(2)).unwrap();
+        assert_eq!(storage_balance.total.0, d(1, 23));
+        assert_eq!(storage_balance.available.0, d(1, 23));
+        testing_env!(context.predecessor_account_id(accounts(2)).attached_deposit(1).build());
+        contract.storage_withdraw(Some(d(4, 22).into()));
+        assert_eq!(contract.storage_balance_of(accounts(2)).unwrap().available.0, d(6, 22));
+
+        // unregister gives back only the NEAR deposited by the farmer
+        let farmer = contract.internal_unwrap_farmer(&accounts(2));
+        assert_eq!(farmer.storage_deposit.saturating_sub(farmer.get_registration_deposit()), d(6, 22));
+        assert!(contract.storage_unregister(None));
+        assert!(contract.storage_balance_of(accounts(2)).is_none());
     }
-}
\ No newline at end of file
+}
@@ -9,6 +9,8 @@ enum TokenReceiverMessage {
     Free,
     Lock { duration_sec: u32 },
     Reward { farm_id: FarmId },
+    /// create a farm and fund it with the attached reward, operators only
+    CreateFarmAndDeposit { seed_id: SeedId, terms: FarmTerms },
 }
 
 #[near_bindgen]
@@ -34,7 +36,24 @@ impl FungibleTokenReceiver for Contract {
             }
             TokenReceiverMessage::Reward { farm_id } => {
                 let (total_amount, start_at) =
-                    self.internal_deposit_reward(&farm_id, &token_id, amount);
+                    self.internal_deposit_reward(&farm_id, &sender_id, &token_id, amount);
+
+                Event::RewardDeposit {
+                    caller_id: &sender_id,
+                    farm_id: &farm_id,
+                    deposit_amount: &U128(amount),
+                    total_amount: &U128(total_amount),
+                    start_at,
+                }
+                .emit();
+            }
+            TokenReceiverMessage::CreateFarmAndDeposit { seed_id, terms } => {
+                require!(self.is_owner_or_operator_account(&sender_id), E002_NOT_ALLOWED);
+                require!(terms.reward_token == token_id, E404_UNMATCHED_REWARD_TOKEN);
+                let farm_id = self.internal_create_farm(&sender_id, &seed_id, &terms);
+
+                let (total_amount, start_at) =
+                    self.internal_deposit_reward(&farm_id, &sender_id, &token_id, amount);
 
                 Event::RewardDeposit {
                     caller_id: &sender_id,
@@ -87,7 +106,8 @@ impl MFTTokenReceiver for Contract {
             TokenReceiverMessage::Lock { duration_sec } => {
                 self.stake_lock_seed(&sender_id, &seed_id, amount, duration_sec);
             }
-            TokenReceiverMessage::Reward { farm_id: _ } => {
+            TokenReceiverMessage::Reward { farm_id: _ }
+            | TokenReceiverMessage::CreateFarmAndDeposit { .. } => {
                 env::panic_str(E601_MFT_CAN_NOT_BE_REWARD)
             }
         }
@@ -96,8 +116,53 @@ impl MFTTokenReceiver for Contract {
 }
 
 
+#[near_bindgen]
+impl Contract {
+    /// Callback on receiving NEP-245 tokens by this contract,
+    /// each token is staked into seed "mt:<token contract>@<token_id>" for its previous owner.
+    /// sender_id may be an approved operator, it is kept only as the NEP-245 arg name.
+    #[allow(unused_variables)]
+    pub fn mt_on_transfer(
+        &mut self,
+        sender_id: AccountId,
+        previous_owner_ids: Vec<AccountId>,
+        token_ids: Vec<String>,
+        amounts: Vec<U128>,
+        msg: String,
+    ) -> PromiseOrValue<Vec<U128>> {
+        require!(self.data().state == RunningState::Running, E004_CONTRACT_PAUSED);
+        require!(
+            !token_ids.is_empty()
+                && token_ids.len() == amounts.len()
+                && token_ids.len() == previous_owner_ids.len(),
+            E602_MT_INVALID_TRANSFER
+        );
+        let message =
+            serde_json::from_str::<TokenReceiverMessage>(&msg).expect(E500_INVALID_MSG);
+        let token = env::predecessor_account_id();
+        for ((farmer_id, token_id), amount) in previous_owner_ids.iter().zip(token_ids.iter()).zip(amounts.iter()) {
+            let seed_id = gen_mt_seed_id(&token, token_id);
+            match &message {
+                TokenReceiverMessage::Free => {
+                    self.stake_free_seed(farmer_id, &seed_id, amount.0);
+                }
+                TokenReceiverMessage::Lock { duration_sec } => {
+                    self.stake_lock_seed(farmer_id, &seed_id, amount.0, *duration_sec);
+                }
+                TokenReceiverMessage::Reward { farm_id: _ }
+                | TokenReceiverMessage::CreateFarmAndDeposit { .. } => {
+                    env::panic_str(E608_MT_CAN_NOT_BE_REWARD)
+                }
+            }
+        }
+        PromiseOrValue::Value(vec![U128(0); token_ids.len()])
+    }
+}
+
+
 impl Contract {
     pub fn stake_free_seed(&mut self, farmer_id: &AccountId, seed_id: &SeedId, amount: u128) {
+        let amount = self.internal_auto_register_farmer(farmer_id, seed_id, amount, true);
         let mut farmer = self.internal_unwrap_farmer(&farmer_id);
         self.internal_do_farmer_claim(&mut farmer, &seed_id);
         self.sync_booster_policy(&mut farmer);
@@ -106,7 +171,7 @@ impl Contract {
 
         let mut farmer_seed = farmer.get_seed_unwrap(&seed_id);
         let increased_seed_power = farmer_seed.add_free(amount);
-        farmer.set_seed(&seed_id, farmer_seed);
+        self.internal_set_farmer_seed(&mut farmer, &mut seed, farmer_seed);
 
         seed.total_seed_amount += amount;
         seed.total_seed_power += increased_seed_power;
@@ -133,6 +198,7 @@ impl Contract {
         amount: u128,
         duration_sec: u32,
     ) {
+        let amount = self.internal_auto_register_farmer(farmer_id, seed_id, amount, true);
         let mut farmer = self.internal_unwrap_farmer(&farmer_id);
         self.internal_do_farmer_claim(&mut farmer, &seed_id);
         self.sync_booster_policy(&mut farmer);
@@ -146,7 +212,7 @@ impl Contract {
 
         let mut farmer_seed = farmer.get_seed_unwrap(&seed_id);
         let increased_seed_power = farmer_seed.add_lock(amount, duration_sec, &config);
-        farmer.set_seed(&seed_id, farmer_seed);
+        self.internal_set_farmer_seed(&mut farmer, &mut seed, farmer_seed);
 
         seed.total_seed_amount += amount;
         seed.total_seed_power += increased_seed_power;
@@ -166,3 +232,24 @@ impl Contract {
         .emit();
     }
 }
+
+#[cfg(test)]
+mod token_receiver_tests {
+    use super::*;
+    use crate::unit_env::*;
+    use near_sdk::test_utils::accounts;
+
+    #[test]
+    fn mt_seed() {
+        let (token, token_id) = parse_seed_id(&"mt:ex@nep141:a.near@1".to_string());
+        assert_eq!(token.to_string(), "ex");
+        assert_eq!(token_id, Some("nep141:a.near@1".to_string()));
+
+        let (mut context, mut contract) = setup_contract();
+        testing_env!(context.predecessor_account_id(accounts(0)).attached_deposit(1).build());
+        contract.create_seed("mt:ex@0".to_string(), 18, Some(0.into()), None);
+        testing_env!(context.predecessor_account_id("ex".parse().unwrap()).attached_deposit(0).build());
+        contract.mt_on_transfer(accounts(2), vec![accounts(1)], vec!["0".to_string()], vec![1000.into()], "\"Free\"".to_string());
+        assert_eq!(contract.get_farmer_seed(accounts(1), "mt:ex@0".to_string()).unwrap().free_amount, 1000);
+    }
+}
@@ -15,20 +15,43 @@ pub const DEFAULT_MAX_LOCKING_DURATION_SEC: DurationSec = 3600 * 24 * 30 * 12;
 pub const DEFAULT_MAX_LOCKING_REWARD_RATIO: u32 = 20000;
 pub const MIN_LOCKING_REWARD_RATIO: u32 = 10000; 
 pub const MAX_NUM_SEEDS_PER_BOOSTER: usize = 16;
+pub const DEFAULT_BOOSTER_DELEGATION_COOLDOWN_SEC: DurationSec = 3600 * 24 * 7;
+pub const DEFAULT_MAX_NUM_FARM_CHECKPOINTS: u32 = 30;
+pub const MAX_NUM_FARM_CHECKPOINTS: u32 = 180;
+pub const DEFAULT_FARM_CLEANUP_GRACE_SEC: DurationSec = 3600 * 24 * 30;
+pub const MAX_NUM_FARMS_CLEANUP_PER_CALL: usize = 8;
+/// each depositor costs a ft_transfer and its callback when refunding, keep them within one call's gas
+pub const MAX_NUM_DEPOSITORS_PER_FARM: usize = 8;
+/// tokens checked in one check_solvency call, each one is a balance query with its callback
+pub const MAX_NUM_SOLVENCY_CHECKS: usize = 12;
+pub const DEFAULT_OUTDATED_FARM_CLAIM_GRACE_SEC: DurationSec = 3600 * 24 * 30;
 pub const AVAILABLE_MS_FOR_NEXT_OWNER_ACCEPT: u64 = 168 * 3600 * 1000;
 
 pub const STORAGE_BALANCE_MIN_BOUND: u128 = 100_000_000_000_000_000_000_000;
+/// storage fee of registering an account on the wNEAR contract
+pub const WNEAR_STORAGE_DEPOSIT: u128 = 1_250_000_000_000_000_000_000;
 pub const TGAS: u64 = 1_000_000_000_000;
 pub const GAS_FOR_SEED_TRANSFER: Gas = Gas(20 * TGAS);
 pub const GAS_FOR_RESOLVE_SEED_TRANSFER: Gas = Gas(10 * TGAS);
 pub const GAS_FOR_REWARD_TRANSFER: Gas = Gas(20 * TGAS);
 pub const GAS_FOR_RESOLVE_REWARD_TRANSFER: Gas = Gas(10 * TGAS);
+pub const GAS_FOR_WNEAR_STORAGE_DEPOSIT: Gas = Gas(10 * TGAS);
+pub const GAS_FOR_WRAP_NEAR: Gas = Gas(10 * TGAS);
+pub const GAS_FOR_RESOLVE_WRAP_NEAR: Gas = Gas(50 * TGAS);
+pub const GAS_FOR_NFT_VALUATION: Gas = Gas(10 * TGAS);
+pub const GAS_FOR_NFT_STAKE_CALLBACK: Gas = Gas(60 * TGAS);
+pub const GAS_FOR_NFT_TRANSFER: Gas = Gas(20 * TGAS);
+pub const GAS_FOR_RESOLVE_NFT_TRANSFER: Gas = Gas(10 * TGAS);
+pub const GAS_FOR_BALANCE_OF: Gas = Gas(10 * TGAS);
+pub const GAS_FOR_RESOLVE_BALANCE_OF: Gas = Gas(5 * TGAS);
 
 pub const NANOS_PER_DAY: Duration = 24 * 60 * 60 * 10u64.pow(9);
 pub const MIN_SEED_DEPOSIT: u128 = 1_000_000_000_000_000_000;
 pub const BP_DENOM: u128 = 10000;
 pub const MFT_TAG: &str = ":";
 pub const SEED_TAG: &str = "@";
+pub const MT_SEED_PREFIX: &str = "mt:";
+pub const NFT_SEED_PREFIX: &str = "nft:";
 pub const FARM_ID_PREFIX: &str = "#";
 pub type SeedId = String;
 pub type FarmId = String;
@@ -103,7 +126,23 @@ pub fn parse_farm_id(farm_id: &FarmId) -> (SeedId, u32) {
     (seed_id.to_string(), (last.split_at(1).1).parse::<u32>().unwrap())
 }
 
+/// NEP-245 seeds are "mt:<contract>@<token_id>", the token_id may contain any char,
+/// NEP-171 seeds are "nft:<contract>" or "nft:<contract>@<token_id_prefix>",
+/// others are "<token>" for FT and "<token>@<token_id>" for MFT.
 pub fn parse_seed_id(seed_id: &SeedId) -> (AccountId, Option<String>) {
+    if let Some(nft_seed) = seed_id.strip_prefix(NFT_SEED_PREFIX) {
+        let v: Vec<&str> = nft_seed.splitn(2, SEED_TAG).collect();
+        let token: AccountId = v[0].parse().expect(E308_INVALID_SEED_ID);
+        return (token, v.get(1).map(|prefix| prefix.to_string()));
+    }
+    if let Some(mt_seed) = seed_id.strip_prefix(MT_SEED_PREFIX) {
+        let v: Vec<&str> = mt_seed.splitn(2, SEED_TAG).collect();
+        if v.len() == 2 && !v[1].is_empty() {
+            let token: AccountId = v[0].parse().expect(E308_INVALID_SEED_ID);
+            return (token, Some(v[1].to_string()));
+        }
+        env::panic_str(E308_INVALID_SEED_ID)
+    }
     let v: Vec<&str> = seed_id.split(SEED_TAG).collect();
     if v.len() == 1 {
         let token: AccountId = v[0].parse().unwrap();
@@ -116,6 +155,18 @@ pub fn parse_seed_id(seed_id: &SeedId) -> (AccountId, Option<String>) {
     }
 }
 
+pub fn is_mt_seed_id(seed_id: &SeedId) -> bool {
+    seed_id.starts_with(MT_SEED_PREFIX)
+}
+
+pub fn is_nft_seed_id(seed_id: &SeedId) -> bool {
+    seed_id.starts_with(NFT_SEED_PREFIX)
+}
+
+pub fn gen_mt_seed_id(token: &AccountId, token_id: &str) -> SeedId {
+    format!("{}{}{}{}", MT_SEED_PREFIX, token, SEED_TAG, token_id)
+}
+
 #[ext_contract(ext_multi_fungible_token)]
 pub trait MultiFungibleToken {
     fn mft_transfer(
@@ -125,6 +176,51 @@ pub trait MultiFungibleToken {
         amount: U128,
         memo: Option<String>,
     );
+
+    fn mft_balance_of(&self, token_id: String, account_id: AccountId);
+}
+
+#[ext_contract(ext_multi_token)]
+pub trait MultiToken {
+    fn mt_transfer(
+        &mut self,
+        receiver_id: AccountId,
+        token_id: String,
+        amount: U128,
+        approval: Option<(AccountId, u64)>,
+    );
+
+    fn mt_balance_of(&self, account_id: AccountId, token_id: String);
+}
+
+#[ext_contract(ext_non_fungible_token)]
+pub trait NonFungibleToken {
+    fn nft_transfer(
+        &mut self,
+        receiver_id: AccountId,
+        token_id: String,
+        approval_id: Option<u64>,
+        memo: Option<String>,
+    );
+
+    fn nft_token(&self, token_id: String);
+}
+
+#[ext_contract(ext_wrap_near)]
+pub trait WrapNear {
+    fn storage_deposit(&mut self, account_id: Option<AccountId>, registration_only: Option<bool>);
+
+    fn near_deposit(&mut self);
+}
+
+#[ext_contract(ext_fungible_token_metadata)]
+pub trait FungibleTokenMetadataProvider {
+    fn ft_metadata(&self);
+}
+
+#[ext_contract(ext_ref_exchange)]
+pub trait RefExchange {
+    fn get_shadow_records(&self, account_id: AccountId);
 }
 
 #[ext_contract(ext_self)]
@@ -140,13 +236,29 @@ pub trait TokenPostActions {
 
     fn callback_withdraw_seed_slashed(&mut self, seed_id: SeedId, amount: U128);
 
+    fn callback_withdraw_seed_register_fee(&mut self, seed_id: SeedId, amount: U128);
+
     fn callback_withdraw_seed_lostfound(&mut self, seed_id: SeedId, sender_id: AccountId, amount: U128);
 
-    fn callback_withdraw_beneficiary(&mut self, farm_id: FarmId, amount: U128);
+    fn callback_withdraw_beneficiary(&mut self, farm_id: FarmId, receiver_id: AccountId, amount: U128);
+
+    fn callback_withdraw_outdated_beneficiary(&mut self, farm_id: FarmId, receiver_id: AccountId, amount: U128);
+
+    fn callback_withdraw_undistributed(&mut self, farm_id: FarmId, receiver_id: AccountId, amount: U128);
+
+    fn callback_sync_shadow_amount(&mut self, farmer_id: AccountId, seed_id: SeedId);
+
+    fn callback_deposit_reward_near(&mut self, farm_id: FarmId, depositor_id: AccountId, wnear_id: AccountId, amount: U128);
+
+    fn callback_refund_reward_near(&mut self, depositor_id: AccountId, wnear_id: AccountId, amount: U128) -> bool;
+
+    fn callback_stake_nft(&mut self, seed_id: SeedId, farmer_id: AccountId, token_id: String) -> bool;
+
+    fn callback_withdraw_nft(&mut self, seed_id: SeedId, farmer_id: AccountId, token_id: String);
 
-    fn callback_withdraw_outdated_beneficiary(&mut self, farm_id: FarmId, amount: U128);
+    fn callback_ft_metadata(&mut self, token_id: AccountId);
 
-    fn callback_withdraw_undistributed(&mut self, farm_id: FarmId, amount: U128);
+    fn callback_check_solvency(&mut self, token_key: String, expected: U128) -> bool;
 }
 
 pub fn wrap_mft_token_id(token_id: &str) -> String {
@@ -16,6 +16,7 @@ pub struct Metadata {
     pub farm_count: U64,
     pub outdated_farm_count: U64,
     pub seed_count: U64,
+    pub farmer_sponsor_pool: U128,
 }
 
 #[derive(Serialize)]
@@ -26,6 +27,91 @@ pub struct StorageReport {
     pub locking_near: U128,
 }
 
+#[derive(Serialize)]
+#[serde(crate = "near_sdk::serde")]
+#[cfg_attr(feature = "test", derive(Deserialize, Clone))]
+pub struct FarmRewardRate {
+    pub farm_id: FarmId,
+    pub reward_token: AccountId,
+    /// daily reward capped by remaining reward
+    pub daily_reward: U128,
+    pub remaining_reward: U128,
+    pub total_seed_power: U128,
+    /// daily reward for each 10^seed_decimal seed power, None if no seed power yet
+    pub daily_reward_per_seed: Option<U128>,
+    pub days_to_end: u32,
+    pub reward_token_metadata: Option<RewardTokenMetadata>,
+}
+
+impl FarmRewardRate {
+    fn new(seed: &Seed, farm: &SeedFarm) -> Self {
+        let daily_reward = farm.get_effective_daily_reward();
+        Self {
+            farm_id: farm.farm_id.clone(),
+            reward_token: farm.terms.reward_token.clone(),
+            daily_reward: daily_reward.into(),
+            remaining_reward: farm.get_remaining_reward().into(),
+            total_seed_power: seed.total_seed_power.into(),
+            daily_reward_per_seed: if seed.total_seed_power > 0 {
+                Some(u128_ratio(daily_reward, 10u128.pow(seed.seed_decimal), seed.total_seed_power).into())
+            } else {
+                None
+            },
+            days_to_end: farm.get_days_to_end(),
+            reward_token_metadata: farm.reward_token_metadata.clone(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(crate = "near_sdk::serde")]
+#[cfg_attr(feature = "test", derive(Deserialize, Clone))]
+pub struct FarmerDailyReward {
+    pub farm_id: FarmId,
+    pub reward_token: AccountId,
+    pub seed_power: U128,
+    pub daily_reward: U128,
+    pub days_to_end: u32,
+}
+
+#[derive(Serialize)]
+#[serde(crate = "near_sdk::serde")]
+#[cfg_attr(feature = "test", derive(Deserialize, Clone))]
+pub struct FarmerRewardInfo {
+    pub amount: U128,
+    pub metadata: Option<RewardTokenMetadata>,
+}
+
+#[derive(Serialize)]
+#[serde(crate = "near_sdk::serde")]
+#[cfg_attr(feature = "test", derive(Deserialize, Clone))]
+pub struct FarmRefundView {
+    pub depositors: HashMap<AccountId, U128>,
+    pub refund_account: Option<AccountId>,
+}
+
+#[derive(Serialize)]
+#[serde(crate = "near_sdk::serde")]
+#[cfg_attr(feature = "test", derive(Deserialize, Clone))]
+pub struct StakeSimulation {
+    pub prev_seed_power: U128,
+    pub seed_power: U128,
+    pub total_seed_power: U128,
+    /// farmer's share of total_seed_power after the stake
+    pub share: f64,
+    pub farm_rewards: Vec<FarmerDailyReward>,
+}
+
+#[derive(Serialize)]
+#[serde(crate = "near_sdk::serde")]
+#[cfg_attr(feature = "test", derive(Deserialize, Clone))]
+pub struct ShadowStakeInfo {
+    pub farmer_id: AccountId,
+    pub seed_id: SeedId,
+    pub shadow_amount: U128,
+    pub shadow_locked_amount: U128,
+}
+
 #[near_bindgen]
 impl Contract {
     //******** Contract Concern */
@@ -42,6 +128,7 @@ impl Contract {
             farm_count: self.data().farm_count.into(),
             outdated_farm_count: self.data().outdated_farms.len().into(),
             seed_count: self.data().seeds.len().into(),
+            farmer_sponsor_pool: self.data().farmer_sponsor_pool.into(),
         }
     }
 
@@ -66,7 +153,7 @@ impl Contract {
         let from_index = from_index.unwrap_or(0);
         let limit = limit.unwrap_or(values.len());
         (from_index..std::cmp::min(values.len(), from_index + limit))
-            .map(|index| values.get(index).unwrap().into())
+            .map(|index| self.internal_fill_farm_metadata(values.get(index).unwrap().into()))
             .collect()
     }
 
@@ -76,11 +163,14 @@ impl Contract {
                 VSeedFarm::V0(farm) => {
                     farm.into()
                 }
+                VSeedFarm::V1(farm) => {
+                    farm.into()
+                }
                 VSeedFarm::Current(farm) => {
                     farm
                 }
             }
-        })
+        }).map(|farm| self.internal_fill_farm_metadata(farm))
     }
 
     /// return slashed seed and its amount in this contract in a hashmap
@@ -101,6 +191,22 @@ impl Contract {
             .collect()
     }
 
+    /// return registration fee paid in seed token and its amount in this contract in a hashmap
+    pub fn list_seeds_register_fee(&self, from_index: Option<u64>, limit: Option<u64>) -> HashMap<SeedId, U128> {
+        let keys = self.data().seeds_register_fee.keys_as_vector();
+
+        let from_index = from_index.unwrap_or(0);
+        let limit = limit.unwrap_or(keys.len());
+
+        (from_index..std::cmp::min(from_index + limit, keys.len()))
+            .map(|index| {
+                let seed_id = keys.get(index).unwrap();
+                let amount = self.data().seeds_register_fee.get(&seed_id).unwrap();
+                (seed_id, amount.into())
+            })
+            .collect()
+    }
+
     /// return lostfound seed and its amount in this contract in a hashmap
     pub fn list_lostfound(&self, from_index: Option<u64>, limit: Option<u64>) -> HashMap<SeedId, U128> {
         let keys = self.data().seeds_lostfound.keys_as_vector();
@@ -118,6 +224,91 @@ impl Contract {
             .collect()
     }
 
+    /// return lostfound seed of the farmer and its amount in a hashmap
+    pub fn get_farmer_lostfound(&self, farmer_id: AccountId) -> HashMap<SeedId, U128> {
+        self.data()
+            .farmer_seeds_lostfound
+            .get(&farmer_id)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(seed_id, amount)| (seed_id, amount.into()))
+            .collect()
+    }
+
+    /// return lostfound reward in a list of (farmer_id, token_id, amount)
+    pub fn list_rewards_lostfound(&self, from_index: Option<u64>, limit: Option<u64>) -> Vec<(AccountId, AccountId, U128)> {
+        let keys = self.data().rewards_lostfound.keys_as_vector();
+
+        let from_index = from_index.unwrap_or(0);
+        let limit = limit.unwrap_or(keys.len());
+
+        (from_index..std::cmp::min(from_index + limit, keys.len()))
+            .map(|index| {
+                let (farmer_id, token_id) = keys.get(index).unwrap();
+                let amount = self.data().rewards_lostfound.get(&(farmer_id.clone(), token_id.clone())).unwrap();
+                (farmer_id, token_id, amount.into())
+            })
+            .collect()
+    }
+
+    /// return lostfound nft in a list of (seed_id, token_id, farmer_id)
+    pub fn list_nfts_lostfound(&self, from_index: Option<u64>, limit: Option<u64>) -> Vec<(SeedId, String, AccountId)> {
+        let keys = self.data().nfts_lostfound.keys_as_vector();
+
+        let from_index = from_index.unwrap_or(0);
+        let limit = limit.unwrap_or(keys.len());
+
+        (from_index..std::cmp::min(from_index + limit, keys.len()))
+            .map(|index| {
+                let (seed_id, token_id) = keys.get(index).unwrap();
+                let farmer_id = self.data().nfts_lostfound.get(&(seed_id.clone(), token_id.clone())).unwrap();
+                (seed_id, token_id, farmer_id)
+            })
+            .collect()
+    }
+
+    /// return registered shadow providers in a hashmap
+    pub fn list_shadow_providers(&self, from_index: Option<u64>, limit: Option<u64>) -> HashMap<AccountId, ShadowProvider> {
+        let keys = self.data().shadow_providers.keys_as_vector();
+
+        let from_index = from_index.unwrap_or(0);
+        let limit = limit.unwrap_or(keys.len());
+
+        (from_index..std::cmp::min(from_index + limit, keys.len()))
+            .map(|index| {
+                let provider_id = keys.get(index).unwrap();
+                let shadow_provider = self.data().shadow_providers.get(&provider_id).unwrap();
+                (provider_id, shadow_provider)
+            })
+            .collect()
+    }
+
+    pub fn get_shadow_provider(&self, provider_id: AccountId) -> Option<ShadowProvider> {
+        self.data().shadow_providers.get(&provider_id)
+    }
+
+    /// return shadow amount of each farmer in each seed, used to reconcile with shadow providers
+    pub fn list_shadow_stakes(&self, from_index: Option<u64>, limit: Option<u64>) -> Vec<ShadowStakeInfo> {
+        let keys = self.data().shadow_stakers.as_vector();
+
+        let from_index = from_index.unwrap_or(0);
+        let limit = limit.unwrap_or(keys.len());
+
+        (from_index..std::cmp::min(from_index + limit, keys.len()))
+            .filter_map(|index| {
+                let (farmer_id, seed_id) = keys.get(index).unwrap();
+                self.internal_get_farmer(&farmer_id)
+                    .and_then(|farmer| farmer.get_seed(&seed_id))
+                    .map(|farmer_seed| ShadowStakeInfo {
+                        farmer_id,
+                        seed_id,
+                        shadow_amount: farmer_seed.shadow_amount.into(),
+                        shadow_locked_amount: farmer_seed.shadow_locked_amount.into(),
+                    })
+            })
+            .collect()
+    }
+
     //******** Seed Concern */
     pub fn list_seeds_info(&self, from_index: Option<u64>, limit: Option<u64>) -> Vec<Seed> {
         let values = self.data().seeds.values_as_vector();
@@ -129,10 +320,7 @@ impl Contract {
     }
 
     pub fn get_seed(&self, seed_id: SeedId) -> Option<Seed> {
-        self.data().seeds.get(&seed_id).map(|vs| {
-            let VSeed::Current(seed) = vs;
-            seed
-        })
+        self.data().seeds.get(&seed_id).map(|vs| vs.into())
     }
 
     pub fn list_seed_farms(&self, seed_id: SeedId) -> Vec<SeedFarm> {
@@ -144,11 +332,15 @@ impl Contract {
                     VSeedFarm::V0(farm) => {
                         farm.clone().into()
                     }
+                    VSeedFarm::V1(farm) => {
+                        farm.clone().into()
+                    }
                     VSeedFarm::Current(farm) => {
                         farm.clone()
                     }
                 }
             })
+            .map(|farm| self.internal_fill_farm_metadata(farm))
             .collect()
     }
 
@@ -164,13 +356,63 @@ impl Contract {
                 VSeedFarm::V0(farm) => {
                     farm.clone().into()
                 }
+                VSeedFarm::V1(farm) => {
+                    farm.clone().into()
+                }
                 VSeedFarm::Current(farm) => {
                     farm.clone()
                 }
             }
+        }).map(|farm| self.internal_fill_farm_metadata(farm))
+    }
+
+    pub fn get_farm_reward_rate(&self, farm_id: FarmId) -> Option<FarmRewardRate> {
+        let (seed_id, _) = parse_farm_id(&farm_id);
+        self.internal_get_seed(&seed_id).and_then(|seed| {
+            seed.farms.get(&farm_id).map(|vf| {
+                let farm = self.internal_fill_farm_metadata(vf.clone().into());
+                FarmRewardRate::new(&seed, &farm)
+            })
         })
     }
 
+    /// return depositors of the farm and the refund account if any
+    pub fn get_farm_refund_info(&self, farm_id: FarmId) -> FarmRefundView {
+        let refund_info = self.data().farm_refunds.get(&farm_id).unwrap_or_default();
+        FarmRefundView {
+            depositors: refund_info.depositors.into_iter().map(|(k, v)| (k, v.into())).collect(),
+            refund_account: refund_info.refund_account,
+        }
+    }
+
+    /// return checkpoints of the farm in time order
+    pub fn list_farm_checkpoints(&self, farm_id: FarmId, from_index: Option<u64>, limit: Option<u64>) -> Vec<FarmCheckpoint> {
+        let checkpoints = self.data().farm_checkpoints.get(&farm_id).unwrap_or_default().to_vec();
+        let from_index = from_index.unwrap_or(0) as usize;
+        let limit = limit.map(|v| v as usize).unwrap_or(checkpoints.len());
+        checkpoints.into_iter().skip(from_index).take(limit).collect()
+    }
+
+    /// return cached metadata of reward tokens in a hashmap
+    pub fn list_reward_tokens(&self, from_index: Option<u64>, limit: Option<u64>) -> HashMap<AccountId, RewardTokenMetadata> {
+        let keys = self.data().reward_tokens.keys_as_vector();
+
+        let from_index = from_index.unwrap_or(0);
+        let limit = limit.unwrap_or(keys.len());
+
+        (from_index..std::cmp::min(from_index + limit, keys.len()))
+            .map(|index| {
+                let token_id = keys.get(index).unwrap();
+                let metadata = self.data().reward_tokens.get(&token_id).unwrap();
+                (token_id, metadata)
+            })
+            .collect()
+    }
+
+    pub fn get_reward_token_metadata(&self, token_id: AccountId) -> Option<RewardTokenMetadata> {
+        self.internal_get_reward_token_metadata(&token_id)
+    }
+
     //******** Farmer Concern */
     pub fn get_unclaimed_rewards(
         &self,
@@ -202,6 +444,78 @@ impl Contract {
         }).collect()
     }
 
+    /// estimate daily reward of each farm in the seed with farmer's current seed power
+    pub fn estimate_farmer_daily_rewards(&self, farmer_id: AccountId, seed_id: SeedId) -> Vec<FarmerDailyReward> {
+        let farmer = self.internal_unwrap_farmer(&farmer_id);
+        let seed = self.internal_unwrap_seed(&seed_id);
+        let seed_power = farmer.get_seed(&seed_id).map(|v| v.get_seed_power()).unwrap_or(0);
+        seed.farms
+            .values()
+            .map(|vf| {
+                let farm: SeedFarm = vf.clone().into();
+                let daily_reward = if seed.total_seed_power > 0 {
+                    u128_ratio(farm.get_effective_daily_reward(), seed_power, seed.total_seed_power)
+                } else {
+                    0
+                };
+                FarmerDailyReward {
+                    farm_id: farm.farm_id.clone(),
+                    reward_token: farm.terms.reward_token.clone(),
+                    seed_power: seed_power.into(),
+                    daily_reward: daily_reward.into(),
+                    days_to_end: farm.get_days_to_end(),
+                }
+            })
+            .collect()
+    }
+
+    /// simulate staking amount of seed, locked if duration_sec > 0, on copies of farmer seed and seed.
+    /// Power change of seeds boosted by this seed is not included.
+    pub fn simulate_stake(&self, farmer_id: AccountId, seed_id: SeedId, amount: U128, duration_sec: u32) -> StakeSimulation {
+        // unregistered accounts simulate as a fresh farmer, nothing is written in a view
+        let farmer = self.internal_get_farmer(&farmer_id).unwrap_or_else(|| Farmer::new(&farmer_id, &farmer_id));
+        let mut seed = self.internal_unwrap_seed(&seed_id);
+        let config = self.internal_config();
+        require!(amount.0 > 0 && amount.0 >= seed.min_deposit, E307_BELOW_MIN_DEPOSIT);
+
+        let mut farmer_seed = farmer.get_seed(&seed_id).unwrap_or_default();
+        let prev_seed_power = farmer_seed.get_seed_power();
+        farmer_seed.boost_ratios = self.gen_booster_ratios(&seed_id, &farmer);
+
+        if duration_sec > 0 {
+            require!(seed.min_locking_duration_sec > 0, E300_FORBID_LOCKING);
+            require!(duration_sec >= seed.min_locking_duration_sec, E201_INVALID_DURATION);
+            require!(duration_sec <= config.maximum_locking_duration_sec, E201_INVALID_DURATION);
+            farmer_seed.add_lock(amount.0, duration_sec, &config);
+        } else {
+            farmer_seed.add_free(amount.0);
+        }
+        let seed_power = farmer_seed.get_seed_power();
+        seed.total_seed_power = seed.total_seed_power - prev_seed_power + seed_power;
+
+        let farm_rewards = seed.farms
+            .values()
+            .map(|vf| {
+                let farm: SeedFarm = vf.clone().into();
+                FarmerDailyReward {
+                    farm_id: farm.farm_id.clone(),
+                    reward_token: farm.terms.reward_token.clone(),
+                    seed_power: seed_power.into(),
+                    daily_reward: u128_ratio(farm.get_effective_daily_reward(), seed_power, seed.total_seed_power).into(),
+                    days_to_end: farm.get_days_to_end(),
+                }
+            })
+            .collect();
+
+        StakeSimulation {
+            prev_seed_power: prev_seed_power.into(),
+            seed_power: seed_power.into(),
+            total_seed_power: seed.total_seed_power.into(),
+            share: seed_power as f64 / seed.total_seed_power as f64,
+            farm_rewards,
+        }
+    }
+
     pub fn list_farmer_seeds(
         &self,
         farmer_id: AccountId,
@@ -230,6 +544,29 @@ impl Contract {
         }
     }
 
+    pub fn list_farmers(&self, from_index: Option<u64>, limit: Option<u64>) -> Vec<AccountId> {
+        let keys = self.data().farmer_ids.as_vector();
+        let from_index = from_index.unwrap_or(0);
+        let limit = limit.unwrap_or(keys.len());
+        (from_index..std::cmp::min(keys.len(), from_index + limit))
+            .map(|index| keys.get(index).unwrap())
+            .collect()
+    }
+
+    /// return farmers currently staking in the seed
+    pub fn list_seed_farmers(&self, seed_id: SeedId, from_index: Option<u64>, limit: Option<u64>) -> Vec<AccountId> {
+        if let Some(seed_farmers) = self.data().seed_farmers.get(&seed_id) {
+            let keys = seed_farmers.as_vector();
+            let from_index = from_index.unwrap_or(0);
+            let limit = limit.unwrap_or(keys.len());
+            (from_index..std::cmp::min(keys.len(), from_index + limit))
+                .map(|index| keys.get(index).unwrap())
+                .collect()
+        } else {
+            vec![]
+        }
+    }
+
     pub fn get_farmer_seed(&self, farmer_id: AccountId, seed_id: SeedId) -> Option<FarmerSeed> {
         if let Some(farmer) = self.internal_get_farmer(&farmer_id) {
             farmer.get_seed(&seed_id)
@@ -250,6 +587,20 @@ impl Contract {
         }
     }
 
+    /// Same as list_farmer_rewards, with cached metadata of each reward token.
+    pub fn list_farmer_rewards_with_metadata(&self, farmer_id: AccountId) -> HashMap<AccountId, FarmerRewardInfo> {
+        if let Some(farmer) = self.internal_get_farmer(&farmer_id) {
+            farmer.rewards.into_iter()
+                .map(|(token_id, amount)| {
+                    let metadata = self.internal_get_reward_token_metadata(&token_id);
+                    (token_id, FarmerRewardInfo { amount: U128(amount), metadata })
+                })
+                .collect()
+        } else {
+            HashMap::new()
+        }
+    }
+
     /// Returns balance of amount of given reward token that ready to withdraw.
     pub fn get_farmer_reward(&self, farmer_id: AccountId, token_id: AccountId) -> U128 {
         if let Some(farmer) = self.internal_get_farmer(&farmer_id) {
@@ -261,6 +612,41 @@ impl Contract {
         }
     }
 
+    /// Returns booster lent out by given farmer, <booster_id, <receiver_id, delegation>>
+    pub fn list_farmer_booster_delegations(&self, farmer_id: AccountId) -> HashMap<SeedId, HashMap<AccountId, BoosterDelegation>> {
+        if let Some(farmer) = self.internal_get_farmer(&farmer_id) {
+            farmer.booster_delegations_out
+        } else {
+            HashMap::new()
+        }
+    }
+
+    /// Returns booster lent to given farmer by others, <booster_id, amount>
+    pub fn list_farmer_booster_received(&self, farmer_id: AccountId) -> HashMap<SeedId, U128> {
+        if let Some(farmer) = self.internal_get_farmer(&farmer_id) {
+            farmer.booster_delegations_in.into_iter()
+                .map(|(booster_id, amount)| (booster_id, U128(amount)))
+                .collect()
+        } else {
+            HashMap::new()
+        }
+    }
+
+    /// farmers registered by sponsor_id, farmers registered before the sponsor index exist
+    /// are listed after being backfilled by owner
+    pub fn list_sponsored_farmers(&self, sponsor_id: AccountId, from_index: Option<u64>, limit: Option<u64>) -> Vec<AccountId> {
+        if let Some(sponsored_farmers) = self.data().sponsored_farmers.get(&sponsor_id) {
+            let keys = sponsored_farmers.as_vector();
+            let from_index = from_index.unwrap_or(0);
+            let limit = limit.unwrap_or(keys.len());
+            (from_index..std::cmp::min(keys.len(), from_index + limit))
+                .map(|index| keys.get(index).unwrap())
+                .collect()
+        } else {
+            vec![]
+        }
+    }
+
     pub fn get_farmer_sponsor(&self, farmer_id: AccountId) -> Option<AccountId> {
         if let Some(farmer) = self.internal_get_farmer(&farmer_id) {
             Some(farmer.sponsor_id)
@@ -1,4 +1,6 @@
 use crate::*;
+use near_sdk::json_types::U128;
+use near_sdk::Balance;
 
 impl Env {
     pub fn modify_booster(
@@ -22,4 +24,40 @@ impl Env {
                 1,
             )
     }
-}
\ No newline at end of file
+
+    pub fn delegate_booster(
+        &self,
+        farmer: &UserAccount,
+        booster_id: &SeedId,
+        receiver: &UserAccount,
+        amount: Balance,
+    ) -> ExecutionResult {
+        farmer
+            .function_call(
+                self.farming_contract.contract.delegate_booster(
+                    booster_id.clone(),
+                    receiver.account_id(),
+                    U128(amount)
+                ),
+                MAX_GAS.0,
+                1,
+            )
+    }
+
+    pub fn revoke_booster_delegation(
+        &self,
+        farmer: &UserAccount,
+        booster_id: &SeedId,
+        receiver: &UserAccount,
+    ) -> ExecutionResult {
+        farmer
+            .function_call(
+                self.farming_contract.contract.revoke_booster_delegation(
+                    booster_id.clone(),
+                    receiver.account_id(),
+                ),
+                MAX_GAS.0,
+                1,
+            )
+    }
+}
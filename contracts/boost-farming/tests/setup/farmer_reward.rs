@@ -35,4 +35,20 @@ impl Env {
                 0,
             )
     }
-}
\ No newline at end of file
+
+    pub fn claim_reward_lostfound(
+        &self,
+        operator: &UserAccount,
+        token_id: &UserAccount,
+    )
+    -> ExecutionResult {
+        operator
+            .function_call(
+                self.farming_contract.contract.claim_reward_lostfound(
+                    token_id.account_id()
+                ),
+                MAX_GAS.0,
+                0,
+            )
+    }
+}
@@ -41,6 +41,38 @@ impl Env {
             )
     }
 
+    pub fn withdraw_lostfound(
+        &self,
+        operator: &UserAccount,
+        seed_id: &SeedId,
+    ) -> ExecutionResult {
+        operator
+            .function_call(
+                self.farming_contract.contract.withdraw_lostfound(
+                    seed_id.clone()
+                ),
+                MAX_GAS.0,
+                1,
+            )
+    }
+
+    pub fn withdraw_nft_seed(
+        &self,
+        operator: &UserAccount,
+        seed_id: &SeedId,
+        token_id: &String,
+    ) -> ExecutionResult {
+        operator
+            .function_call(
+                self.farming_contract.contract.withdraw_nft_seed(
+                    seed_id.clone(),
+                    token_id.clone(),
+                ),
+                MAX_GAS.0,
+                1,
+            )
+    }
+
     pub fn force_unlock(
         &self,
         operator: &UserAccount,
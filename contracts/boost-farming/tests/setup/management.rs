@@ -1,5 +1,6 @@
 use crate::*;
 use near_sdk::json_types::U128;
+use near_sdk::Balance;
 
 
 impl Env {
@@ -22,6 +23,24 @@ impl Env {
         )
     }
 
+    pub fn modify_farm_pause_when_empty(
+        &self,
+        operator: &UserAccount,
+        farm_id: &FarmId, 
+        pause_when_empty: bool,
+        deposit: u128
+    ) -> ExecutionResult {
+        operator
+        .function_call(
+            self.farming_contract.contract.modify_farm_pause_when_empty(
+                farm_id.clone(),
+                pause_when_empty
+            ),
+            MAX_GAS.0,
+            deposit,
+        )
+    }
+
     pub fn modify_locking_policy(
         &self,
         operator: &UserAccount,
@@ -57,6 +76,56 @@ impl Env {
     }
 
 
+    pub fn modify_farm_cleanup_grace_period(
+        &self,
+        operator: &UserAccount,
+        grace_sec: u32,
+        deposit: u128
+    ) -> ExecutionResult {
+        operator
+            .function_call(
+                self.farming_contract.contract.modify_farm_cleanup_grace_period(
+                    grace_sec
+                ),
+                MAX_GAS.0,
+                deposit,
+            )
+    }
+
+    pub fn modify_farm_checkpoint_policy(
+        &self,
+        operator: &UserAccount,
+        interval_sec: u32,
+        max_num: u32,
+        deposit: u128
+    ) -> ExecutionResult {
+        operator
+            .function_call(
+                self.farming_contract.contract.modify_farm_checkpoint_policy(
+                    interval_sec,
+                    max_num
+                ),
+                MAX_GAS.0,
+                deposit,
+            )
+    }
+
+    pub fn modify_outdated_farm_claim_grace_period(
+        &self,
+        operator: &UserAccount,
+        grace_sec: u32,
+        deposit: u128
+    ) -> ExecutionResult {
+        operator
+            .function_call(
+                self.farming_contract.contract.modify_outdated_farm_claim_grace_period(
+                    grace_sec
+                ),
+                MAX_GAS.0,
+                deposit,
+            )
+    }
+
     pub fn modify_default_slash_rate(
         &self,
         operator: &UserAccount,
@@ -109,6 +178,85 @@ impl Env {
             )
     }
 
+    pub fn modify_seed_register_fee(
+        &self,
+        operator: &UserAccount,
+        seed_id: &String, 
+        register_fee: Balance,
+        deposit: u128
+    ) -> ExecutionResult {
+        operator
+            .function_call(
+                self.farming_contract.contract.modify_seed_register_fee(
+                    seed_id.clone(),
+                    register_fee.into()
+                ),
+                MAX_GAS.0,
+                deposit,
+            )
+    }
+
+    pub fn modify_auto_register_farmer(
+        &self,
+        operator: &UserAccount,
+        enabled: bool,
+        deposit: u128
+    ) -> ExecutionResult {
+        operator
+            .function_call(
+                self.farming_contract.contract.modify_auto_register_farmer(
+                    enabled
+                ),
+                MAX_GAS.0,
+                deposit,
+            )
+    }
+
+    pub fn modify_wnear_id(
+        &self,
+        operator: &UserAccount,
+        wnear_id: Option<&UserAccount>,
+        deposit: u128
+    ) -> ExecutionResult {
+        operator
+            .function_call(
+                self.farming_contract.contract.modify_wnear_id(
+                    wnear_id.map(|token| token.account_id())
+                ),
+                MAX_GAS.0,
+                deposit,
+            )
+    }
+
+    pub fn deposit_farmer_sponsor_pool(
+        &self,
+        operator: &UserAccount,
+        deposit: u128
+    ) -> ExecutionResult {
+        operator
+            .function_call(
+                self.farming_contract.contract.deposit_farmer_sponsor_pool(),
+                MAX_GAS.0,
+                deposit,
+            )
+    }
+
+    pub fn withdraw_farmer_sponsor_pool(
+        &self,
+        operator: &UserAccount,
+        amount: Balance,
+        deposit: u128
+    ) -> ExecutionResult {
+        operator
+            .function_call(
+                self.farming_contract.contract.withdraw_farmer_sponsor_pool(
+                    amount.into()
+                ),
+                MAX_GAS.0,
+                deposit,
+            )
+    }
+
     pub fn modify_seed_slash_rate(
         &self,
         operator: &UserAccount,
@@ -143,6 +291,22 @@ impl Env {
         )
     }
 
+    pub fn withdraw_seed_register_fee(
+        &self,
+        operator: &UserAccount,
+        seed_id: &SeedId,
+        deposit: u128
+    ) -> ExecutionResult {
+        operator
+        .function_call(
+            self.farming_contract.contract.withdraw_seed_register_fee(
+                seed_id.clone(),
+            ),
+            MAX_GAS.0,
+            deposit,
+        )
+    }
+
     pub fn return_seed_lostfound(
         &self,
         operator: &UserAccount,
@@ -163,6 +327,42 @@ impl Env {
         )
     }
 
+    pub fn modify_seed_nft_valuation(
+        &self,
+        operator: &UserAccount,
+        seed_id: &SeedId, 
+        nft_valuation: Option<NftValuation>,
+        deposit: u128
+    ) -> ExecutionResult {
+        operator
+        .function_call(
+            self.farming_contract.contract.modify_seed_nft_valuation(
+                seed_id.clone(),
+                nft_valuation
+            ),
+            MAX_GAS.0,
+            deposit,
+        )
+    }
+
+    pub fn return_nft_lostfound(
+        &self,
+        operator: &UserAccount,
+        seed_id: &SeedId, 
+        token_id: &String,
+        deposit: u128
+    ) -> ExecutionResult {
+        operator
+        .function_call(
+            self.farming_contract.contract.return_nft_lostfound(
+                seed_id.clone(),
+                token_id.clone()
+            ),
+            MAX_GAS.0,
+            deposit,
+        )
+    }
+
     pub fn withdraw_from_beneficiary_account(
         &self,
         operator: &UserAccount,
@@ -213,4 +413,22 @@ impl Env {
         )
     }
 
-}
\ No newline at end of file
+    pub fn set_farm_refund_account(
+        &self,
+        operator: &UserAccount,
+        farm_id: &FarmId, 
+        refund_account: Option<&UserAccount>,
+        deposit: u128
+    ) -> ExecutionResult {
+        operator
+        .function_call(
+            self.farming_contract.contract.set_farm_refund_account(
+                farm_id.clone(),
+                refund_account.map(|v| v.account_id())
+            ),
+            MAX_GAS.0,
+            deposit,
+        )
+    }
+
+}
@@ -9,18 +9,19 @@ pub use near_sdk_sim::{
 
 use mock_mft::ContractContract as MockMultiFungibleToken;
 pub use boost_farming::{ContractContract as FarmingContract,
-    MIN_SEED_DEPOSIT, DEFAULT_SEED_SLASH_RATE, NANOS_PER_DAY, DEFAULT_MAX_LOCKING_DURATION_SEC, DEFAULT_SEED_MIN_LOCKING_DURATION_SEC, DEFAULT_MAX_LOCKING_REWARD_RATIO, MIN_LOCKING_REWARD_RATIO, DEFAULT_MAX_NUM_FARMS_PER_SEED, BP_DENOM, MAX_NUM_SEEDS_PER_BOOSTER,
-    Seed, SeedFarm, SeedId, FarmId, FarmTerms, FarmStatus, FarmerSeed, RunningState, ImportFarmerInfo, ImportSeedInfo
+    MIN_SEED_DEPOSIT, DEFAULT_SEED_SLASH_RATE, NANOS_PER_DAY, DEFAULT_MAX_LOCKING_DURATION_SEC, DEFAULT_SEED_MIN_LOCKING_DURATION_SEC, DEFAULT_MAX_LOCKING_REWARD_RATIO, MIN_LOCKING_REWARD_RATIO, DEFAULT_MAX_NUM_FARMS_PER_SEED, BP_DENOM, MAX_NUM_SEEDS_PER_BOOSTER, DEFAULT_BOOSTER_DELEGATION_COOLDOWN_SEC, MAX_NUM_FARM_CHECKPOINTS, MAX_NUM_DEPOSITORS_PER_FARM, MAX_NUM_SOLVENCY_CHECKS,
+    Seed, SeedFarm, SeedId, FarmId, FarmTerms, FarmOptions, FarmStatus, FarmerSeed, RunningState, ImportFarmerInfo, ImportSeedInfo, ShadowProvider, ShadowSeedRule, NftValuation
 };
 
 pub use boost_farming::{
-    E000_ALREADY_INIT, E001_PROMISE_RESULT_COUNT_INVALID, E002_NOT_ALLOWED, E003_NOT_INIT, E004_CONTRACT_PAUSED, E005_NOT_ALLOWED_ON_CUR_STATE, E006_NOT_IMPLEMENTED, E007_INVALID_OPERATOR,
-    E100_ACC_NOT_REGISTERED, E101_INSUFFICIENT_BALANCE, E102_INSUFFICIENT_STORAGE, E103_STILL_HAS_REWARD, E104_STILL_HAS_SEED,
-    E200_INVALID_RATIO, E201_INVALID_DURATION, E202_FORBID_SELF_BOOST, E203_EXCEED_FARM_NUM_IN_BOOST, E204_EXCEED_SEED_NUM_IN_BOOSTER, E205_INVALID_SLASH_RATE, E207_FORBID_BOOST_BOOSTER_SEED,
+    E000_ALREADY_INIT, E001_PROMISE_RESULT_COUNT_INVALID, E002_NOT_ALLOWED, E003_NOT_INIT, E004_CONTRACT_PAUSED, E005_NOT_ALLOWED_ON_CUR_STATE, E006_NOT_IMPLEMENTED, E007_INVALID_OPERATOR, E010_EXCEED_SOLVENCY_CHECK_NUM,
+    E100_ACC_NOT_REGISTERED, E101_INSUFFICIENT_BALANCE, E102_INSUFFICIENT_STORAGE, E103_STILL_HAS_REWARD, E104_STILL_HAS_SEED, E105_STILL_HAS_DELEGATION, E106_RECEIVER_INSUFFICIENT_STORAGE,
+    E200_INVALID_RATIO, E201_INVALID_DURATION, E202_FORBID_SELF_BOOST, E203_EXCEED_FARM_NUM_IN_BOOST, E204_EXCEED_SEED_NUM_IN_BOOSTER, E205_INVALID_SLASH_RATE, E207_FORBID_BOOST_BOOSTER_SEED, E208_NOT_BOOSTER_SEED, E209_FORBID_SELF_DELEGATION, E210_EXCEED_DELEGATABLE_AMOUNT, E211_DELEGATION_NOT_EXIST, E212_DELEGATION_IN_COOLDOWN, E213_INVALID_CHECKPOINT_NUM,
     E300_FORBID_LOCKING, E301_SEED_NOT_EXIST, E302_SEED_ALREADY_EXIST, E303_EXCEED_FARM_NUM_IN_SEED, E304_CAUSE_PRE_UNLOCK, E305_STILL_IN_LOCK, E306_LOCK_AMOUNT_TOO_SMALL, E307_BELOW_MIN_DEPOSIT, E308_INVALID_SEED_ID, E309_NO_NEED_FORCE,
-    E401_FARM_NOT_EXIST, E403_FARM_ALREADY_DEPOSIT_REWARD, E404_UNMATCHED_REWARD_TOKEN, E405_FARM_NOT_ENDED, E406_INVALID_FARM_ID,
-    E500_INVALID_MSG,
-    E600_MFT_INVALID_TOKEN_ID, E601_MFT_CAN_NOT_BE_REWARD
+    E401_FARM_NOT_EXIST, E403_FARM_ALREADY_DEPOSIT_REWARD, E404_UNMATCHED_REWARD_TOKEN, E405_FARM_NOT_ENDED, E406_INVALID_FARM_ID, E407_WNEAR_NOT_SET,
+    E500_INVALID_MSG, E502_INVALID_SHADOW_ID,
+    E600_MFT_INVALID_TOKEN_ID, E601_MFT_CAN_NOT_BE_REWARD, E603_NFT_VALUATION_NOT_SET, E604_NFT_ALREADY_STAKED, E605_NFT_NOT_STAKED, E606_NFT_SEED_OPERATION, E607_NFT_NOT_IN_LOSTFOUND, E608_MT_CAN_NOT_BE_REWARD,
+    E700_SHADOW_PROVIDER_NOT_EXIST, E701_INVALID_SHADOW_ID, E702_SHADOW_SEED_NOT_ALLOWED, E703_SHADOW_SYNC_FAILED, E704_SHADOW_NOT_CAST_BY_PROVIDER, E705_SHADOW_PROVIDER_STILL_ACTIVE
 };
 
 mod users;
@@ -47,6 +48,8 @@ mod storage_impl;
 pub use storage_impl::*;
 mod booster;
 pub use booster::*;
+mod shadow;
+pub use shadow::*;
 
 near_sdk_sim::lazy_static_include::lazy_static_include_bytes! {
     PREV_BOOST_FARMING_WASM_BYTES => "../../releases/boost_farming_041_release.wasm",
@@ -54,6 +57,8 @@ near_sdk_sim::lazy_static_include::lazy_static_include_bytes! {
 
     FUNGIBLE_TOKEN_WASM_BYTES => "../../res/mock_ft.wasm",
     MULTI_FUNGIBLE_TOKEN_WASM_BYTES => "../../res/mock_mft.wasm",
+    MULTI_TOKEN_WASM_BYTES => "../../res/mock_mt.wasm",
+    NON_FUNGIBLE_TOKEN_WASM_BYTES => "../../res/mock_nft.wasm",
 }
 
 pub fn previous_boost_farm_wasm_bytes() -> &'static [u8] {
@@ -183,6 +188,14 @@ impl Env {
     pub fn mft_seed_id(&self, token_id: &String) -> String{
         format!("{}@{}", self.multi_fungible_token_contract.user_account.account_id, token_id)
     }
+
+    pub fn mt_seed_id(&self, token: &UserAccount, token_id: &String) -> String{
+        format!("mt:{}@{}", token.account_id, token_id)
+    }
+
+    pub fn nft_seed_id(&self, token: &UserAccount) -> String{
+        format!("nft:{}", token.account_id)
+    }
 }
 
 pub fn d(value: Balance, decimals: u8) -> Balance {
@@ -219,6 +232,28 @@ pub fn init_token(e: &Env, token_account_id: &AccountId, decimals: u8) -> UserAc
     token
 }
 
+pub fn init_mt(e: &Env, token_account_id: &AccountId) -> UserAccount {
+    e.near.deploy_and_init(
+        &MULTI_TOKEN_WASM_BYTES,
+        token_account_id.clone(),
+        "new",
+        &json!({}).to_string().into_bytes(),
+        to_yocto("10"),
+        DEFAULT_GAS.0,
+    )
+}
+
+pub fn init_nft(e: &Env, token_account_id: &AccountId) -> UserAccount {
+    e.near.deploy_and_init(
+        &NON_FUNGIBLE_TOKEN_WASM_BYTES,
+        token_account_id.clone(),
+        "new",
+        &json!({}).to_string().into_bytes(),
+        to_yocto("10"),
+        DEFAULT_GAS.0,
+    )
+}
+
 #[macro_export]
 macro_rules! assert_err{
     (print $exec_func: expr)=>{
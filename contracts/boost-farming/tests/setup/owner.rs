@@ -1,4 +1,6 @@
 use crate::*;
+use std::collections::HashMap;
+use near_sdk::json_types::U128;
 
 
 impl Env {
@@ -116,4 +118,54 @@ impl Env {
             deposit,
         )
     }
-}
\ No newline at end of file
+
+    pub fn set_shadow_provider(
+        &self, 
+        operator: &UserAccount,
+        provider: &UserAccount,
+        shadow_provider: ShadowProvider,
+        deposit: u128
+    ) -> ExecutionResult {
+        operator
+            .function_call(
+                self.farming_contract.contract.set_shadow_provider(
+                    provider.account_id(),
+                    shadow_provider,
+                ),
+                MAX_GAS.0,
+                deposit,
+            )
+    }
+
+    pub fn remove_shadow_provider(
+        &self, 
+        operator: &UserAccount,
+        provider: &UserAccount,
+        deposit: u128
+    ) -> ExecutionResult {
+        operator
+            .function_call(
+                self.farming_contract.contract.remove_shadow_provider(
+                    provider.account_id(),
+                ),
+                MAX_GAS.0,
+                deposit,
+            )
+    }
+
+    pub fn check_solvency(
+        &self, 
+        operator: &UserAccount,
+        liabilities: HashMap<String, U128>,
+        deposit: u128
+    ) -> ExecutionResult {
+        operator
+            .function_call(
+                self.farming_contract.contract.check_solvency(
+                    liabilities,
+                ),
+                MAX_GAS.0,
+                deposit,
+            )
+    }
+}
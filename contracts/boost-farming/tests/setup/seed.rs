@@ -31,16 +31,30 @@ impl Env {
         reward_token: &UserAccount,
         start_at: u32,
         daily_reward: Balance
+    ) -> ExecutionResult {
+        self.create_farm_with_terms(
+            operator,
+            seed_id,
+            FarmTerms{
+                reward_token: reward_token.account_id(),
+                start_at,
+                daily_reward,
+                options: FarmOptions::default(),
+            },
+        )
+    }
+
+    pub fn create_farm_with_terms(
+        &self,
+        operator: &UserAccount,
+        seed_id: &SeedId,
+        terms: FarmTerms,
     ) -> ExecutionResult {
         operator
             .function_call(
                 self.farming_contract.contract.create_farm(
                     seed_id.clone(),
-                    FarmTerms{
-                        reward_token: reward_token.account_id(),
-                        start_at,
-                        daily_reward
-                    },
+                    terms,
                 ),
                 MAX_GAS.0,
                 1,
@@ -76,4 +90,35 @@ impl Env {
                 1,
             )
     }
-}
\ No newline at end of file
+
+    pub fn cleanup_ended_farms(
+        &self,
+        user: &UserAccount,
+        seed_id: &SeedId
+    ) -> ExecutionResult {
+        user
+            .function_call(
+                self.farming_contract.contract.cleanup_ended_farms(
+                    seed_id.clone(),
+                ),
+                MAX_GAS.0,
+                0,
+            )
+    }
+
+    pub fn deposit_reward_near(
+        &self,
+        user: &UserAccount,
+        farm_id: &FarmId,
+        amount: Balance
+    ) -> ExecutionResult {
+        user
+            .function_call(
+                self.farming_contract.contract.deposit_reward_near(
+                    farm_id.clone(),
+                ),
+                MAX_GAS.0,
+                amount,
+            )
+    }
+}
@@ -0,0 +1,85 @@
+use crate::*;
+use near_sdk::json_types::U128;
+use near_sdk::Balance;
+
+impl Env {
+    pub fn on_cast_shadow(
+        &self,
+        provider: &UserAccount,
+        farmer: &UserAccount,
+        shadow_id: &str,
+        amount: Balance,
+        msg: &str,
+    ) -> ExecutionResult {
+        provider
+            .function_call(
+                self.farming_contract.contract.on_cast_shadow(
+                    farmer.account_id(),
+                    shadow_id.to_string(),
+                    U128(amount),
+                    msg.to_string(),
+                ),
+                MAX_GAS.0,
+                0,
+            )
+    }
+
+    pub fn on_remove_shadow(
+        &self,
+        provider: &UserAccount,
+        farmer: &UserAccount,
+        shadow_id: &str,
+        amount: Balance,
+        msg: &str,
+    ) -> ExecutionResult {
+        provider
+            .function_call(
+                self.farming_contract.contract.on_remove_shadow(
+                    farmer.account_id(),
+                    shadow_id.to_string(),
+                    U128(amount),
+                    msg.to_string(),
+                ),
+                MAX_GAS.0,
+                0,
+            )
+    }
+
+    pub fn sync_shadow_amount(
+        &self,
+        operator: &UserAccount,
+        farmer: &UserAccount,
+        seed_id: &SeedId,
+        deposit: u128,
+    ) -> ExecutionResult {
+        operator
+            .function_call(
+                self.farming_contract.contract.sync_shadow_amount(
+                    farmer.account_id(),
+                    seed_id.clone(),
+                ),
+                MAX_GAS.0,
+                deposit,
+            )
+    }
+
+    pub fn remove_orphan_shadow(
+        &self,
+        operator: &UserAccount,
+        farmer: &UserAccount,
+        provider: &UserAccount,
+        seed_id: &SeedId,
+        deposit: u128,
+    ) -> ExecutionResult {
+        operator
+            .function_call(
+                self.farming_contract.contract.remove_orphan_shadow(
+                    farmer.account_id(),
+                    provider.account_id(),
+                    seed_id.clone(),
+                ),
+                MAX_GAS.0,
+                deposit,
+            )
+    }
+}
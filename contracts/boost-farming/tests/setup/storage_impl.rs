@@ -1,4 +1,7 @@
 use crate::*;
+use near_sdk::serde_json::Value;
+use near_sdk::{AccountId, Balance};
+
 impl Env {
     pub fn storage_deposit (
         &self,
@@ -21,11 +24,20 @@ impl Env {
         &self,
         operator: &UserAccount,
         deposit: u128
+    ) -> ExecutionResult {
+        self.storage_withdraw_amount(operator, None, deposit)
+    }
+
+    pub fn storage_withdraw_amount(
+        &self,
+        operator: &UserAccount,
+        amount: Option<Balance>,
+        deposit: u128
     ) -> ExecutionResult {
         operator
             .function_call(
                 self.farming_contract.contract.storage_withdraw(
-                    None,
+                    amount.map(|v| v.into()),
                 ),
                 MAX_GAS.0,
                 deposit,
@@ -46,4 +58,34 @@ impl Env {
                 deposit,
             )
     }
-}
\ No newline at end of file
+
+    pub fn storage_balance_of(&self, user: &UserAccount) -> Value {
+        self.owner
+        .view_method_call(
+            self.farming_contract.contract.storage_balance_of(user.account_id())
+        ).unwrap_json_value()
+    }
+
+    pub fn reclaim_storage(
+        &self,
+        operator: &UserAccount,
+        farmer: &UserAccount,
+        deposit: u128
+    ) -> ExecutionResult {
+        operator
+            .function_call(
+                self.farming_contract.contract.reclaim_storage(
+                    farmer.account_id(),
+                ),
+                MAX_GAS.0,
+                deposit,
+            )
+    }
+
+    pub fn list_sponsored_farmers(&self, sponsor: &UserAccount) -> Vec<AccountId> {
+        self.owner
+        .view_method_call(
+            self.farming_contract.contract.list_sponsored_farmers(sponsor.account_id(), None, None)
+        ).unwrap_json::<Vec<AccountId>>()
+    }
+}
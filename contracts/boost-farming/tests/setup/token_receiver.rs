@@ -26,6 +26,39 @@ impl Env {
         )
     }
 
+    pub fn create_farm_and_deposit(
+        &self,
+        token: &UserAccount,
+        user: &UserAccount,
+        amount: Balance,
+        seed_id: &SeedId,
+        start_at: u32,
+        daily_reward: Balance,
+    ) -> ExecutionResult {
+        user.call(
+            token.account_id.clone(),
+            "ft_transfer_call",
+            &json!({
+                "receiver_id": self.farming_contract.user_account.account_id(),
+                "amount": U128::from(amount),
+                "msg": json!({
+                    "CreateFarmAndDeposit": {
+                        "seed_id": seed_id.clone(),
+                        "terms": {
+                            "reward_token": token.account_id(),
+                            "start_at": start_at,
+                            "daily_reward": U128::from(daily_reward),
+                        }
+                    }
+                }).to_string(),
+            })
+            .to_string()
+            .into_bytes(),
+            MAX_GAS.0,
+            1,
+        )
+    }
+
     pub fn mft_stake_free_seed(
         &self,
         user: &UserAccount,
@@ -113,4 +146,60 @@ impl Env {
             1,
         )
     }
-}
\ No newline at end of file
+
+    pub fn mt_transfer_call(
+        &self,
+        token: &UserAccount,
+        user: &UserAccount,
+        token_id: &String,
+        amount: Balance,
+        msg: String,
+    ) -> ExecutionResult {
+        user.call(
+            token.account_id.clone(),
+            "mt_transfer_call",
+            &json!({
+                "receiver_id": self.farming_contract.user_account.account_id(),
+                "token_id": token_id.clone(),
+                "amount": U128::from(amount),
+                "msg": msg,
+            })
+            .to_string()
+            .into_bytes(),
+            MAX_GAS.0,
+            1,
+        )
+    }
+
+    pub fn mt_stake_free_seed(
+        &self,
+        token: &UserAccount,
+        user: &UserAccount,
+        token_id: &String,
+        amount: Balance,
+    ) -> ExecutionResult {
+        self.mt_transfer_call(token, user, token_id, amount, "\"Free\"".to_string())
+    }
+
+    pub fn nft_stake_free_seed(
+        &self,
+        token: &UserAccount,
+        user: &UserAccount,
+        token_id: &String,
+        seed_id: &SeedId,
+    ) -> ExecutionResult {
+        user.call(
+            token.account_id.clone(),
+            "nft_transfer_call",
+            &json!({
+                "receiver_id": self.farming_contract.user_account.account_id(),
+                "token_id": token_id.clone(),
+                "msg": json!({ "Free": { "seed_id": seed_id.clone() } }).to_string(),
+            })
+            .to_string()
+            .into_bytes(),
+            MAX_GAS.0,
+            1,
+        )
+    }
+}
@@ -1,8 +1,8 @@
 use crate::*;
 pub use near_sdk_sim::UserAccount;
-use near_sdk::serde_json::json;
+use near_sdk::serde_json::{json, Value};
 use near_sdk::json_types::U128;
-use near_sdk::Balance;
+use near_sdk::{AccountId, Balance};
 use near_sdk::env;
 
 pub struct Tokens {
@@ -119,5 +119,81 @@ impl Env {
             ).unwrap_json();
         amount.0
     }
-}
 
+    pub fn mt_mint(&self, token: &UserAccount, token_id: &String, user: &UserAccount, amount: Balance) {
+        self.owner
+            .call(
+                token.account_id.clone(),
+                "mint",
+                &json!({
+                    "token_id": token_id.clone(),
+                    "account_id": user.account_id(),
+                    "amount": U128::from(amount),
+                })
+                .to_string()
+                .into_bytes(),
+                DEFAULT_GAS.0,
+                0,
+            )
+            .assert_success();
+    }
+
+    pub fn mt_balance_of(&self, token: &UserAccount, user_account: &UserAccount, token_id: &String) -> u128{
+        let amount: U128 = self.owner
+            .view(
+                token.account_id.clone(),
+                "mt_balance_of",
+                &json!({
+                    "account_id": user_account.account_id(),
+                    "token_id": token_id.clone(),
+                }).to_string().into_bytes()
+            ).unwrap_json();
+        amount.0
+    }
+
+    pub fn nft_mint(&self, token: &UserAccount, token_id: &String, user: &UserAccount, liquidity: Balance) {
+        self.owner
+            .call(
+                token.account_id.clone(),
+                "mint",
+                &json!({
+                    "token_id": token_id.clone(),
+                    "owner_id": user.account_id(),
+                    "liquidity": U128::from(liquidity),
+                })
+                .to_string()
+                .into_bytes(),
+                DEFAULT_GAS.0,
+                0,
+            )
+            .assert_success();
+    }
+
+    pub fn nft_set_transfer_paused(&self, token: &UserAccount, paused: bool) {
+        self.owner
+            .call(
+                token.account_id.clone(),
+                "set_transfer_paused",
+                &json!({
+                    "paused": paused,
+                })
+                .to_string()
+                .into_bytes(),
+                DEFAULT_GAS.0,
+                0,
+            )
+            .assert_success();
+    }
+
+    pub fn nft_owner_of(&self, token: &UserAccount, token_id: &String) -> AccountId{
+        let nft: Value = self.owner
+            .view(
+                token.account_id.clone(),
+                "nft_token",
+                &json!({
+                    "token_id": token_id.clone(),
+                }).to_string().into_bytes()
+            ).unwrap_json_value();
+        account_id(nft["owner_id"].as_str().unwrap())
+    }
+}
@@ -17,6 +17,14 @@ pub fn assert_seed(seed: Value, seed_id: &SeedId, seed_decimal: u32, next_index:
     assert_eq!(json!(min_locking_duration_sec), *seed.get("min_locking_duration_sec").unwrap());
 }
 
+pub fn assert_seed_stats(seed: Value, farmer_count: u64, total_free_amount: Balance, total_locked_amount: Balance, total_x_locked_amount: Balance, total_shadow_amount: Balance){
+    assert_eq!(json!(farmer_count), *seed.get("farmer_count").unwrap());
+    assert_eq!(total_free_amount.to_string(), *seed.get("total_free_amount").unwrap());
+    assert_eq!(total_locked_amount.to_string(), *seed.get("total_locked_amount").unwrap());
+    assert_eq!(total_x_locked_amount.to_string(), *seed.get("total_x_locked_amount").unwrap());
+    assert_eq!(total_shadow_amount.to_string(), *seed.get("total_shadow_amount").unwrap());
+}
+
 pub fn assert_farm_info(seed_farm: SeedFarm, farm_id: &FarmId, reward_token: &UserAccount, start_at: u32, daily_reward: Balance) {
     assert_eq!(seed_farm.farm_id, farm_id.clone());
     assert_eq!(seed_farm.terms.reward_token, reward_token.account_id());
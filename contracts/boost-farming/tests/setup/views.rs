@@ -4,7 +4,7 @@ use near_sdk::serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use near_sdk::serde_json::Value;
 use near_sdk::json_types::{U64, U128};
-use near_sdk::AccountId;
+use near_sdk::{AccountId, Balance};
 
 #[derive(Serialize, Deserialize, Clone)]
 #[serde(crate = "near_sdk::serde")]
@@ -32,6 +32,7 @@ pub struct Metadata {
     pub farm_count: U64,
     pub outdated_farm_count: U64,
     pub seed_count: U64,
+    pub farmer_sponsor_pool: U128,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -41,6 +42,15 @@ pub struct StorageReport {
     pub locking_near: U128,
 }
 
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ShadowStakeInfo {
+    pub farmer_id: AccountId,
+    pub seed_id: SeedId,
+    pub shadow_amount: U128,
+    pub shadow_locked_amount: U128,
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 #[serde(crate = "near_sdk::serde")]
 pub struct BoosterInfo {
@@ -59,6 +69,8 @@ pub struct Config {
     pub max_num_farms_per_seed: u32,
     pub maximum_locking_duration_sec: u32,
     pub max_locking_multiplier: u32,
+    pub farm_checkpoint_interval_sec: u32,
+    pub max_num_farm_checkpoints: u32,
 }
 
 impl Env {
@@ -183,10 +195,115 @@ impl Env {
         ).unwrap_json::<HashMap<SeedId, U128>>()
     }
 
+    pub fn list_seeds_register_fee(&self) -> HashMap<SeedId, U128>{
+        self.owner
+        .view_method_call(
+            self.farming_contract.contract.list_seeds_register_fee(None, None)
+        ).unwrap_json::<HashMap<SeedId, U128>>()
+    }
+
     pub fn list_lostfound(&self) -> HashMap<SeedId, U128>{
         self.owner
         .view_method_call(
             self.farming_contract.contract.list_lostfound(None, None)
         ).unwrap_json::<HashMap<SeedId, U128>>()
     }
-}
\ No newline at end of file
+
+    pub fn get_farmer_lostfound(&self, user: &UserAccount) -> HashMap<SeedId, U128>{
+        self.owner
+        .view_method_call(
+            self.farming_contract.contract.get_farmer_lostfound(user.account_id())
+        ).unwrap_json::<HashMap<SeedId, U128>>()
+    }
+
+    pub fn list_rewards_lostfound(&self) -> Vec<(AccountId, AccountId, U128)>{
+        self.owner
+        .view_method_call(
+            self.farming_contract.contract.list_rewards_lostfound(None, None)
+        ).unwrap_json::<Vec<(AccountId, AccountId, U128)>>()
+    }
+
+    pub fn list_nfts_lostfound(&self) -> Vec<(SeedId, String, AccountId)>{
+        self.owner
+        .view_method_call(
+            self.farming_contract.contract.list_nfts_lostfound(None, None)
+        ).unwrap_json::<Vec<(SeedId, String, AccountId)>>()
+    }
+
+    pub fn get_liabilities(&self, from_index: Option<u64>, limit: Option<u64>) -> HashMap<String, U128>{
+        self.owner
+        .view_method_call(
+            self.farming_contract.contract.get_liabilities(from_index, limit)
+        ).unwrap_json::<HashMap<String, U128>>()
+    }
+
+    pub fn list_farmer_booster_received(&self, user: &UserAccount) -> HashMap<SeedId, U128>{
+        self.owner
+        .view_method_call(
+            self.farming_contract.contract.list_farmer_booster_received(user.account_id())
+        ).unwrap_json::<HashMap<SeedId, U128>>()
+    }
+
+    pub fn get_shadow_provider(&self, provider: &UserAccount) -> Option<ShadowProvider>{
+        self.owner
+        .view_method_call(
+            self.farming_contract.contract.get_shadow_provider(provider.account_id())
+        ).unwrap_json::<Option<ShadowProvider>>()
+    }
+
+    pub fn list_shadow_stakes(&self) -> Vec<ShadowStakeInfo>{
+        self.owner
+        .view_method_call(
+            self.farming_contract.contract.list_shadow_stakes(None, None)
+        ).unwrap_json::<Vec<ShadowStakeInfo>>()
+    }
+
+    pub fn simulate_stake(&self, user: &UserAccount, seed_id: &SeedId, amount: Balance, duration_sec: u32) -> Value{
+        self.owner
+        .view_method_call(
+            self.farming_contract.contract.simulate_stake(user.account_id(), seed_id.clone(), U128(amount), duration_sec)
+        ).unwrap_json_value()
+    }
+
+    pub fn get_farm_reward_rate(&self, farm_id: &FarmId) -> Value{
+        self.owner
+        .view_method_call(
+            self.farming_contract.contract.get_farm_reward_rate(farm_id.clone())
+        ).unwrap_json_value()
+    }
+
+    pub fn estimate_farmer_daily_rewards(&self, user: &UserAccount, seed_id: &SeedId) -> Value{
+        self.owner
+        .view_method_call(
+            self.farming_contract.contract.estimate_farmer_daily_rewards(user.account_id(), seed_id.clone())
+        ).unwrap_json_value()
+    }
+
+    pub fn list_farm_checkpoints(&self, farm_id: &FarmId) -> Vec<Value>{
+        self.owner
+        .view_method_call(
+            self.farming_contract.contract.list_farm_checkpoints(farm_id.clone(), None, None)
+        ).unwrap_json::<Vec<Value>>()
+    }
+
+    pub fn list_farmers(&self) -> Vec<AccountId>{
+        self.owner
+        .view_method_call(
+            self.farming_contract.contract.list_farmers(None, None)
+        ).unwrap_json::<Vec<AccountId>>()
+    }
+
+    pub fn list_seed_farmers(&self, seed_id: &SeedId) -> Vec<AccountId>{
+        self.owner
+        .view_method_call(
+            self.farming_contract.contract.list_seed_farmers(seed_id.clone(), None, None)
+        ).unwrap_json::<Vec<AccountId>>()
+    }
+
+    pub fn get_farm_refund_info(&self, farm_id: &FarmId) -> Value{
+        self.owner
+        .view_method_call(
+            self.farming_contract.contract.get_farm_refund_info(farm_id.clone())
+        ).unwrap_json_value()
+    }
+}
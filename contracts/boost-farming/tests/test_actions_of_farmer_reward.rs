@@ -130,6 +130,15 @@ fn test_withdraw_reward(){
         "The account farmer1.near is not registered"
     );
 
+    // failed withdrawal of a registered farmer is reverted, not lostfound
+    assert!(e.list_rewards_lostfound().is_empty());
+
+    // 3 : E101_INSUFFICIENT_BALANCE
+    assert_err!(
+        e.claim_reward_lostfound(&users.farmer1, &tokens.nref),
+        E101_INSUFFICIENT_BALANCE
+    );
+
     e.ft_storage_deposit(&users.farmer1, &tokens.nref);
     assert_eq!(e.get_farmer_reward(&users.farmer1, &tokens.nref), to_yocto("10"));
     assert_eq!(true, e.withdraw_reward(&users.farmer1, &tokens.nref, None).unwrap_json::<bool>());
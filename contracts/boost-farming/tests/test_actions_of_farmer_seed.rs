@@ -149,6 +149,7 @@ fn test_unlock_and_withdraw_seed(){
     );
     e.mft_storage_deposit(&token_id, &users.farmer1);
     assert_eq!(e.list_lostfound().get(&seed_id).unwrap().0, to_yocto("25"));
+    assert_eq!(e.get_farmer_lostfound(&users.farmer1).get(&seed_id).unwrap().0, to_yocto("25"));
 
     // success
     assert_eq!(e.mft_balance_of(&users.farmer1, &token_id), 0);
@@ -220,4 +221,169 @@ fn test_force_unlock(){
     let free_amount = to_yocto("100") - seed_slashed;
     assert_user_seed_info(e.get_farmer_seed(&users.farmer1, &seed_id), free_amount, 0, 0, 0, 0);
     assert_eq!(e.list_slashed().get(&seed_id).unwrap().0, seed_slashed);
-}
\ No newline at end of file
+}
+#[test]
+fn test_simulate_stake(){
+    let e = init_env();
+    let users = Users::init(&e);
+    let tokens = Tokens::init(&e);
+
+    let inner_id = "0".to_string();
+    let token_id = format!(":{}", inner_id);
+    let seed_id = e.mft_seed_id(&inner_id);
+    let farm_id = format!("{}#{}", seed_id, 0);
+
+    e.create_seed(&e.owner, &seed_id, TOKEN_DECIMALS as u32, None, None).assert_success();
+    e.mft_mint(&inner_id, &users.farmer1, to_yocto("100"));
+    e.storage_deposit_self_to_farming(&users.farmer1).assert_success();
+    e.storage_deposit_self_to_farming(&users.farmer2).assert_success();
+    e.mft_storage_deposit(&token_id, &e.farming_contract.user_account);
+    e.mft_stake_free_seed(&users.farmer1, &token_id, to_yocto("100")).assert_success();
+
+    e.create_farm(&e.owner, &seed_id, &tokens.nref, to_sec(e.current_time()), to_yocto("10")).assert_success();
+    e.ft_mint(&tokens.nref, &users.operator, to_yocto("10000"));
+    e.deposit_reward(&tokens.nref, &users.operator, to_yocto("100"), &farm_id).assert_success();
+
+    let simulation = e.simulate_stake(&users.farmer2, &seed_id, to_yocto("100"), DEFAULT_MAX_LOCKING_DURATION_SEC);
+    assert_eq!(simulation["prev_seed_power"], "0");
+    assert_eq!(simulation["seed_power"], to_yocto("200").to_string());
+    assert_eq!(simulation["total_seed_power"], to_yocto("300").to_string());
+    assert!((simulation["share"].as_f64().unwrap() - 2f64 / 3f64).abs() < 1e-6);
+    assert_eq!(simulation["farm_rewards"][0]["farm_id"], farm_id);
+    assert_eq!(simulation["farm_rewards"][0]["daily_reward"], (to_yocto("20") / 3).to_string());
+    assert_eq!(simulation["farm_rewards"][0]["days_to_end"], 10);
+
+    let simulation = e.simulate_stake(&users.farmer1, &seed_id, to_yocto("100"), 0);
+    assert_eq!(simulation["prev_seed_power"], to_yocto("100").to_string());
+    assert_eq!(simulation["seed_power"], to_yocto("200").to_string());
+    assert_eq!(simulation["share"].as_f64().unwrap(), 1.0);
+
+    // unregistered account simulates as a new farmer
+    let simulation = e.simulate_stake(&users.alice, &seed_id, to_yocto("100"), 0);
+    assert_eq!(simulation["prev_seed_power"], "0");
+    assert_eq!(simulation["seed_power"], to_yocto("100").to_string());
+    assert_eq!(simulation["total_seed_power"], to_yocto("200").to_string());
+    assert_eq!(simulation["farm_rewards"][0]["daily_reward"], to_yocto("5").to_string());
+
+    // state not changed
+    assert!(e.get_farmer_seed(&users.farmer2, &seed_id).is_null());
+    assert_eq!(e.get_seed(&seed_id)["total_seed_power"], to_yocto("100").to_string());
+}
+
+#[test]
+fn test_farmer_index(){
+    let e = init_env();
+    let users = Users::init(&e);
+
+    let inner_id = "0".to_string();
+    let token_id = format!(":{}", inner_id);
+    let seed_id = e.mft_seed_id(&inner_id);
+
+    e.create_seed(&e.owner, &seed_id, TOKEN_DECIMALS as u32, None, None).assert_success();
+    e.mft_mint(&inner_id, &users.farmer1, to_yocto("100"));
+    e.mft_mint(&inner_id, &users.farmer2, to_yocto("100"));
+    assert!(e.list_farmers().is_empty());
+
+    e.storage_deposit_self_to_farming(&users.farmer1).assert_success();
+    e.storage_deposit_self_to_farming(&users.farmer2).assert_success();
+    e.mft_storage_deposit(&token_id, &e.farming_contract.user_account);
+    assert_eq!(e.list_farmers(), vec![users.farmer1.account_id(), users.farmer2.account_id()]);
+    assert!(e.list_seed_farmers(&seed_id).is_empty());
+
+    e.mft_stake_free_seed(&users.farmer1, &token_id, to_yocto("100")).assert_success();
+    e.mft_stake_free_seed(&users.farmer2, &token_id, to_yocto("100")).assert_success();
+    assert_eq!(e.list_seed_farmers(&seed_id), vec![users.farmer1.account_id(), users.farmer2.account_id()]);
+
+    // farmer leaves seed index once all seed withdrawn
+    e.unlock_and_withdraw_seed(&users.farmer1, &seed_id, 0, to_yocto("100")).assert_success();
+    assert_eq!(e.list_seed_farmers(&seed_id), vec![users.farmer2.account_id()]);
+
+    // and leaves farmer index when unregistered
+    e.storage_unregister(&users.farmer1, 1).assert_success();
+    assert_eq!(e.list_farmers(), vec![users.farmer2.account_id()]);
+}
+
+#[test]
+fn test_seed_farmer_stats(){
+    let e = init_env();
+    let users = Users::init(&e);
+
+    let inner_id = "0".to_string();
+    let token_id = format!(":{}", inner_id);
+    let seed_id = e.mft_seed_id(&inner_id);
+
+    e.create_seed(&e.owner, &seed_id, TOKEN_DECIMALS as u32, None, None).assert_success();
+    assert_seed_stats(e.get_seed(&seed_id), 0, 0, 0, 0, 0);
+    e.mft_mint(&inner_id, &users.farmer1, to_yocto("100"));
+    e.mft_mint(&inner_id, &users.farmer2, to_yocto("100"));
+    e.storage_deposit_self_to_farming(&users.farmer1).assert_success();
+    e.storage_deposit_self_to_farming(&users.farmer2).assert_success();
+    e.mft_storage_deposit(&token_id, &e.farming_contract.user_account);
+
+    e.mft_stake_free_seed(&users.farmer1, &token_id, to_yocto("100")).assert_success();
+    e.mft_stake_lock_seed(&token_id, &users.farmer2, to_yocto("50"), DEFAULT_MAX_LOCKING_DURATION_SEC).assert_success();
+    assert_seed_stats(e.get_seed(&seed_id), 2, to_yocto("100"), to_yocto("50"), to_yocto("100"), 0);
+
+    e.lock_free_seed(&users.farmer1, &seed_id, DEFAULT_MAX_LOCKING_DURATION_SEC, to_yocto("40")).assert_success();
+    assert_seed_stats(e.get_seed(&seed_id), 2, to_yocto("60"), to_yocto("90"), to_yocto("180"), 0);
+
+    e.unlock_and_withdraw_seed(&users.farmer1, &seed_id, 0, to_yocto("60")).assert_success();
+    assert_seed_stats(e.get_seed(&seed_id), 2, 0, to_yocto("90"), to_yocto("180"), 0);
+
+    e.skip_time(DEFAULT_MAX_LOCKING_DURATION_SEC + 1);
+    e.unlock_and_withdraw_seed(&users.farmer1, &seed_id, to_yocto("40"), to_yocto("40")).assert_success();
+    assert_seed_stats(e.get_seed(&seed_id), 1, 0, to_yocto("50"), to_yocto("100"), 0);
+}
+
+#[test]
+fn test_withdraw_lostfound(){
+    let e = init_env();
+    let users = Users::init(&e);
+
+    let inner_id = "0".to_string();
+    let token_id = format!(":{}", inner_id);
+    let seed_id = e.mft_seed_id(&inner_id);
+
+    e.create_seed(&e.owner, &seed_id, TOKEN_DECIMALS as u32, None, None).assert_success();
+    e.mft_storage_deposit(&token_id, &e.farming_contract.user_account);
+    for farmer in [&users.farmer1, &users.farmer2] {
+        e.mft_mint(&inner_id, farmer, to_yocto("100"));
+        e.storage_deposit_self_to_farming(farmer).assert_success();
+        e.mft_stake_free_seed(farmer, &token_id, to_yocto("100")).assert_success();
+        e.mft_unregister(&token_id, farmer);
+    }
+    e.unlock_and_withdraw_seed(&users.farmer1, &seed_id, 0, to_yocto("100"));
+    e.unlock_and_withdraw_seed(&users.farmer2, &seed_id, 0, to_yocto("40"));
+    assert_eq!(e.list_lostfound().get(&seed_id).unwrap().0, to_yocto("140"));
+    assert_eq!(e.get_farmer_lostfound(&users.farmer1).get(&seed_id).unwrap().0, to_yocto("100"));
+    assert_eq!(e.get_farmer_lostfound(&users.farmer2).get(&seed_id).unwrap().0, to_yocto("40"));
+
+    // error scene
+    // 1 : E101_INSUFFICIENT_BALANCE
+    assert_err!(
+        e.withdraw_lostfound(&users.bob, &seed_id),
+        E101_INSUFFICIENT_BALANCE
+    );
+
+    // failed again goes back to lostfound
+    e.withdraw_lostfound(&users.farmer1, &seed_id);
+    assert_eq!(e.list_lostfound().get(&seed_id).unwrap().0, to_yocto("140"));
+    assert_eq!(e.get_farmer_lostfound(&users.farmer1).get(&seed_id).unwrap().0, to_yocto("100"));
+
+    // success
+    e.mft_storage_deposit(&token_id, &users.farmer1);
+    e.withdraw_lostfound(&users.farmer1, &seed_id).assert_success();
+    assert_eq!(e.mft_balance_of(&users.farmer1, &token_id), to_yocto("100"));
+    assert_eq!(e.list_lostfound().get(&seed_id).unwrap().0, to_yocto("40"));
+    assert!(e.get_farmer_lostfound(&users.farmer1).is_empty());
+
+    // returned by owner can not be withdrawn again
+    e.mft_storage_deposit(&token_id, &users.farmer2);
+    e.return_seed_lostfound(&e.owner, &users.farmer2, &seed_id, to_yocto("40"), 1).assert_success();
+    assert_eq!(e.mft_balance_of(&users.farmer2, &token_id), to_yocto("40"));
+    assert!(e.get_farmer_lostfound(&users.farmer2).is_empty());
+    assert_err!(
+        e.withdraw_lostfound(&users.farmer2, &seed_id),
+        E101_INSUFFICIENT_BALANCE
+    );
+}
@@ -96,6 +96,51 @@ fn test_create_farm(){
     );
 }
 
+#[test]
+fn test_farm_reward_rate(){
+    let e = init_env();
+    let users = Users::init(&e);
+    let tokens = Tokens::init(&e);
+
+    let inner_id = "0".to_string();
+    let token_id = format!(":{}", inner_id);
+    let seed_id = e.mft_seed_id(&inner_id);
+    let farm_id = format!("{}#{}", seed_id, 0);
+
+    // not exist
+    assert!(e.get_farm_reward_rate(&farm_id).is_null());
+    e.create_seed(&e.owner, &seed_id, TOKEN_DECIMALS as u32, None, None).assert_success();
+    assert!(e.get_farm_reward_rate(&farm_id).is_null());
+
+    e.mft_mint(&inner_id, &users.farmer1, to_yocto("100"));
+    e.storage_deposit_self_to_farming(&users.farmer1).assert_success();
+    e.mft_storage_deposit(&token_id, &e.farming_contract.user_account);
+    e.mft_stake_free_seed(&users.farmer1, &token_id, to_yocto("100")).assert_success();
+
+    let start_at = to_sec(e.current_time()) + 24 * 3600;
+    e.create_farm(&e.owner, &seed_id, &tokens.nref, start_at, to_yocto("10")).assert_success();
+    e.ft_mint(&tokens.nref, &users.operator, to_yocto("10000"));
+    e.deposit_reward(&tokens.nref, &users.operator, to_yocto("100"), &farm_id).assert_success();
+
+    // not started yet, nothing distributed daily
+    let rate = e.get_farm_reward_rate(&farm_id);
+    assert_eq!(rate["daily_reward"], "0");
+    assert_eq!(rate["daily_reward_per_seed"], "0");
+    assert_eq!(rate["remaining_reward"], to_yocto("100").to_string());
+    assert_eq!(rate["total_seed_power"], to_yocto("100").to_string());
+    assert_eq!(rate["days_to_end"], 11);
+    assert_eq!(e.estimate_farmer_daily_rewards(&users.farmer1, &seed_id)[0]["daily_reward"], "0");
+
+    e.skip_time(24 * 3600);
+    let rate = e.get_farm_reward_rate(&farm_id);
+    assert_eq!(rate["daily_reward"], to_yocto("10").to_string());
+    assert_eq!(rate["daily_reward_per_seed"], to_yocto("0.1").to_string());
+    assert_eq!(rate["days_to_end"], 10);
+    let farmer_rewards = e.estimate_farmer_daily_rewards(&users.farmer1, &seed_id);
+    assert_eq!(farmer_rewards[0]["farm_id"], farm_id);
+    assert_eq!(farmer_rewards[0]["daily_reward"], to_yocto("10").to_string());
+}
+
 #[test]
 fn test_cancle_farm() {
     let e = init_env();
@@ -230,4 +275,148 @@ fn test_remove_farm_from_seed(){
     e.remove_farm_from_seed(&e.owner, &farm_id).assert_success();
     assert_eq!(e.get_metadata().farm_count.0, 0);
     assert_eq!(e.get_metadata().outdated_farm_count.0, 1);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_cleanup_ended_farms(){
+    let e = init_env();
+    let users = Users::init(&e);
+    let tokens = Tokens::init(&e);
+
+    let inner_id = "0".to_string();
+    let seed_id = e.mft_seed_id(&inner_id);
+    let farm_id = format!("{}#{}", seed_id, 0);
+    let running_farm_id = format!("{}#{}", seed_id, 1);
+
+    e.create_seed(&e.owner, &seed_id, TOKEN_DECIMALS as u32, None, None).assert_success();
+    let start_at = e.current_time();
+    e.create_farm(&e.owner, &seed_id, &tokens.nref, to_sec(start_at), to_yocto("10")).assert_success();
+    e.create_farm(&e.owner, &seed_id, &tokens.nref, to_sec(start_at), to_yocto("10")).assert_success();
+    e.ft_mint(&tokens.nref, &users.operator, to_yocto("1010"));
+    e.deposit_reward(&tokens.nref, &users.operator, to_yocto("10"), &farm_id).assert_success();
+    e.deposit_reward(&tokens.nref, &users.operator, to_yocto("1000"), &running_farm_id).assert_success();
+
+    // error scene
+    // 1 : E301_SEED_NOT_EXIST
+    assert_err!(
+        e.cleanup_ended_farms(&users.alice, &"seed_id".to_string()),
+        E301_SEED_NOT_EXIST
+    );
+
+    // still in grace period
+    e.skip_time(to_sec(NANOS_PER_DAY * 2));
+    let cleaned: Vec<FarmId> = e.cleanup_ended_farms(&users.alice, &seed_id).unwrap_json();
+    assert!(cleaned.is_empty());
+    assert_eq!(e.get_farm(&farm_id).ended_at, start_at + NANOS_PER_DAY);
+    assert_eq!(e.get_metadata().farm_count.0, 2);
+
+    // success
+    e.modify_farm_cleanup_grace_period(&e.owner, to_sec(NANOS_PER_DAY), 1).assert_success();
+    let cleaned: Vec<FarmId> = e.cleanup_ended_farms(&users.alice, &seed_id).unwrap_json();
+    assert_eq!(cleaned, vec![farm_id.clone()]);
+    assert_eq!(e.get_metadata().farm_count.0, 1);
+    assert_eq!(e.get_metadata().outdated_farm_count.0, 1);
+    assert_eq!(e.get_outdated_farm(&farm_id).amount_of_beneficiary, to_yocto("10"));
+    assert_eq!(e.list_seed_farms(&seed_id).len(), 1);
+}
+
+#[test]
+fn test_claim_from_outdated_farm(){
+    let e = init_env();
+    let users = Users::init(&e);
+    let tokens = Tokens::init(&e);
+
+    let inner_id = "0".to_string();
+    let token_id = format!(":{}", inner_id);
+    let seed_id = e.mft_seed_id(&inner_id);
+    let farm_id = format!("{}#{}", seed_id, 0);
+
+    e.create_seed(&e.owner, &seed_id, TOKEN_DECIMALS as u32, None, None).assert_success();
+    e.mft_storage_deposit(&token_id, &e.farming_contract.user_account);
+    for farmer in [&users.farmer1, &users.farmer2] {
+        e.mft_mint(&inner_id, farmer, to_yocto("100"));
+        e.storage_deposit_self_to_farming(farmer).assert_success();
+        e.mft_stake_free_seed(farmer, &token_id, to_yocto("100")).assert_success();
+    }
+
+    let start_at = e.current_time();
+    e.create_farm(&e.owner, &seed_id, &tokens.nref, to_sec(start_at), to_yocto("10")).assert_success();
+    e.set_farm_refund_account(&e.owner, &farm_id, Some(&e.owner), 1).assert_success();
+    e.ft_mint(&tokens.nref, &users.operator, to_yocto("20"));
+    e.deposit_reward(&tokens.nref, &users.operator, to_yocto("20"), &farm_id).assert_success();
+
+    e.skip_time(to_sec(NANOS_PER_DAY * 2));
+    e.modify_outdated_farm_claim_grace_period(&e.owner, to_sec(NANOS_PER_DAY), 1).assert_success();
+    e.remove_farm_from_seed(&e.owner, &farm_id).assert_success();
+    let outdated_farm = e.get_outdated_farm(&farm_id);
+    assert_eq!(outdated_farm.amount_of_beneficiary, 0);
+    assert_eq!(outdated_farm.claimable_until, e.current_time() + NANOS_PER_DAY);
+
+    // farmers keep their rewards of the outdated farm
+    assert_eq!(e.get_unclaimed_rewards(&users.farmer1, &seed_id, &tokens.nref), to_yocto("10"));
+    assert_eq!(e.get_unclaimed_rewards(&users.farmer2, &seed_id, &tokens.nref), to_yocto("10"));
+    e.claim_reward_by_seed(&users.farmer1, &seed_id).assert_success();
+    assert_eq!(e.get_farmer_reward(&users.farmer1, &tokens.nref), to_yocto("10"));
+    assert_eq!(e.get_unclaimed_rewards(&users.farmer1, &seed_id, &tokens.nref), 0);
+    assert_eq!(e.get_outdated_farm(&farm_id).claimed_reward, to_yocto("10"));
+
+    // new farmer gets nothing from the outdated farm
+    e.mft_mint(&inner_id, &users.bob, to_yocto("100"));
+    e.storage_deposit_self_to_farming(&users.bob).assert_success();
+    e.mft_stake_free_seed(&users.bob, &token_id, to_yocto("100")).assert_success();
+    assert_eq!(e.get_unclaimed_rewards(&users.bob, &seed_id, &tokens.nref), 0);
+
+    // after grace period, the rest goes to beneficiary
+    e.skip_time(to_sec(NANOS_PER_DAY));
+    assert_eq!(e.get_unclaimed_rewards(&users.farmer2, &seed_id, &tokens.nref), 0);
+    e.ft_storage_deposit(&e.owner, &tokens.nref);
+    e.withdraw_from_outdated_beneficiary_account(&e.owner, &farm_id, 1).assert_success();
+    assert_eq!(e.ft_balance_of(&tokens.nref, &e.owner), to_yocto("10"));
+    let outdated_farm = e.get_outdated_farm(&farm_id);
+    assert_eq!(outdated_farm.amount_of_beneficiary, to_yocto("10"));
+    assert_eq!(outdated_farm.amount_of_withdrew_beneficiary, to_yocto("10"));
+    assert_eq!(outdated_farm.claimable_until, 0);
+}
+
+#[test]
+fn test_deposit_reward_near(){
+    let e = init_env();
+    let users = Users::init(&e);
+    let tokens = Tokens::init(&e);
+
+    let inner_id = "0".to_string();
+    let seed_id = e.mft_seed_id(&inner_id);
+    let farm_id = format!("{}#{}", seed_id, 0);
+
+    e.create_seed(&e.owner, &seed_id, TOKEN_DECIMALS as u32, None, None).assert_success();
+    let start_at = e.current_time();
+    e.create_farm(&e.owner, &seed_id, &tokens.wnear, to_sec(start_at), to_yocto("10")).assert_success();
+
+    // error scene
+    // 1 : E407_WNEAR_NOT_SET
+    assert_err!(
+        e.deposit_reward_near(&users.operator, &farm_id, to_yocto("10")),
+        E407_WNEAR_NOT_SET
+    );
+
+    // 2 : E002_NOT_ALLOWED
+    assert_err!(
+        e.modify_wnear_id(&users.alice, Some(&tokens.wnear), 1),
+        E002_NOT_ALLOWED
+    );
+
+    // 3 : E404_UNMATCHED_REWARD_TOKEN
+    e.modify_wnear_id(&e.owner, Some(&tokens.nref), 1).assert_success();
+    assert_err!(
+        e.deposit_reward_near(&users.operator, &farm_id, to_yocto("10")),
+        E404_UNMATCHED_REWARD_TOKEN
+    );
+
+    // mock wnear can not wrap, NEAR is refunded
+    e.modify_wnear_id(&e.owner, Some(&tokens.wnear), 1).assert_success();
+    let user_balance_before = users.operator.account().unwrap().amount;
+    e.deposit_reward_near(&users.operator, &farm_id, to_yocto("10"));
+    let user_balance_after = users.operator.account().unwrap().amount;
+    assert!(user_balance_before - user_balance_after < to_yocto("0.1"));
+    assert_farm_detail(e.get_farm(&farm_id), 0, start_at, 0, 0, 0, 0, Some(FarmStatus::Created));
+}
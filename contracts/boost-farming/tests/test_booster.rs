@@ -119,4 +119,146 @@ fn test_modify_booster(){
         e.modify_booster(&e.owner, &booster_id, &booster_info),
         E207_FORBID_BOOST_BOOSTER_SEED
     );
-}
\ No newline at end of file
+}
+#[test]
+fn test_booster_delegation(){
+    let e = init_env();
+    let users = Users::init(&e);
+    let tokens = Tokens::init(&e);
+
+    let inner_id = "0".to_string();
+    let token_id = format!(":{}", inner_id);
+    let seed_id = e.mft_seed_id(&inner_id);
+
+    let booster_id = tokens.love_ref.account_id.to_string();
+    let mut affected_seeds = HashMap::new();
+    affected_seeds.insert(seed_id.clone(), 10);
+    let booster_info = BoosterInfo { booster_decimal: 24, affected_seeds: affected_seeds.clone(), boost_suppress_factor: 1};
+
+    e.create_seed(&e.owner, &seed_id, TOKEN_DECIMALS as u32, None, None).assert_success();
+    e.create_seed(&e.owner, &booster_id, TOKEN_DECIMALS as u32, None, Some(0)).assert_success();
+    e.modify_booster(&e.owner, &booster_id, &booster_info).assert_success();
+
+    e.storage_deposit_self_to_farming(&users.farmer1).assert_success();
+    e.storage_deposit_self_to_farming(&users.farmer2).assert_success();
+
+    println!("> farmer1 stake booster, farmer2 stake seed at : {}", e.current_time());
+    e.ft_mint(&tokens.love_ref, &users.farmer1, to_yocto("100"));
+    e.ft_stake_free_seed(&users.farmer1, &tokens.love_ref, to_yocto("100")).assert_success();
+    e.mft_mint(&inner_id, &users.farmer2, to_yocto("10"));
+    e.mft_storage_deposit(&token_id, &e.farming_contract.user_account);
+    e.mft_stake_free_seed(&users.farmer2, &token_id, to_yocto("10")).assert_success();
+    assert!(e.get_farmer_seed(&users.farmer2, &seed_id)["boost_ratios"].get(&booster_id).is_none());
+
+    // error scene 
+    // 1 : E208_NOT_BOOSTER_SEED
+    assert_err!(
+        e.delegate_booster(&users.farmer1, &seed_id, &users.farmer2, to_yocto("100")),
+        E208_NOT_BOOSTER_SEED
+    );
+
+    // 2 : E209_FORBID_SELF_DELEGATION
+    assert_err!(
+        e.delegate_booster(&users.farmer1, &booster_id, &users.farmer1, to_yocto("100")),
+        E209_FORBID_SELF_DELEGATION
+    );
+
+    // 3 : E100_ACC_NOT_REGISTERED
+    assert_err!(
+        e.delegate_booster(&users.farmer1, &booster_id, &users.alice, to_yocto("100")),
+        E100_ACC_NOT_REGISTERED
+    );
+
+    // 4 : E210_EXCEED_DELEGATABLE_AMOUNT
+    assert_err!(
+        e.delegate_booster(&users.farmer1, &booster_id, &users.farmer2, to_yocto("101")),
+        E210_EXCEED_DELEGATABLE_AMOUNT
+    );
+
+    // 5 : E211_DELEGATION_NOT_EXIST
+    assert_err!(
+        e.revoke_booster_delegation(&users.farmer1, &booster_id, &users.farmer2),
+        E211_DELEGATION_NOT_EXIST
+    );
+
+    // success
+    e.delegate_booster(&users.farmer1, &booster_id, &users.farmer2, to_yocto("100")).assert_success();
+    assert_eq!(e.list_farmer_booster_received(&users.farmer2).get(&booster_id).unwrap().0, to_yocto("100"));
+    let ratio = e.get_farmer_seed(&users.farmer2, &seed_id)["boost_ratios"][&booster_id].as_f64().unwrap();
+    assert!((ratio - 2f64).abs() < 1e-6);
+
+    // 6 : E210_EXCEED_DELEGATABLE_AMOUNT, lent booster can not be withdrawn
+    assert_err!(
+        e.unlock_and_withdraw_seed(&users.farmer1, &booster_id, 0, to_yocto("1")),
+        E210_EXCEED_DELEGATABLE_AMOUNT
+    );
+
+    // 7 : E212_DELEGATION_IN_COOLDOWN
+    assert_err!(
+        e.revoke_booster_delegation(&users.farmer1, &booster_id, &users.farmer2),
+        E212_DELEGATION_IN_COOLDOWN
+    );
+
+    e.skip_time(DEFAULT_BOOSTER_DELEGATION_COOLDOWN_SEC);
+    e.revoke_booster_delegation(&users.farmer1, &booster_id, &users.farmer2).assert_success();
+    assert!(e.list_farmer_booster_received(&users.farmer2).is_empty());
+    assert!(e.get_farmer_seed(&users.farmer2, &seed_id)["boost_ratios"].get(&booster_id).is_none());
+    e.unlock_and_withdraw_seed(&users.farmer1, &booster_id, 0, to_yocto("100")).assert_success();
+}
+
+#[test]
+fn test_booster_delegation_locked_power(){
+    let e = init_env();
+    let users = Users::init(&e);
+    let tokens = Tokens::init(&e);
+
+    let inner_id = "0".to_string();
+    let token_id = format!(":{}", inner_id);
+    let seed_id = e.mft_seed_id(&inner_id);
+
+    let booster_id = tokens.love_ref.account_id.to_string();
+    let mut affected_seeds = HashMap::new();
+    affected_seeds.insert(seed_id.clone(), 10);
+    let booster_info = BoosterInfo { booster_decimal: 24, affected_seeds: affected_seeds.clone(), boost_suppress_factor: 1};
+
+    e.create_seed(&e.owner, &seed_id, TOKEN_DECIMALS as u32, None, None).assert_success();
+    e.create_seed(&e.owner, &booster_id, TOKEN_DECIMALS as u32, None, None).assert_success();
+    e.modify_booster(&e.owner, &booster_id, &booster_info).assert_success();
+
+    e.storage_deposit_self_to_farming(&users.farmer1).assert_success();
+    e.storage_deposit_self_to_farming(&users.farmer2).assert_success();
+
+    e.ft_mint(&tokens.love_ref, &users.farmer1, to_yocto("100"));
+    e.ft_stake_free_seed(&users.farmer1, &tokens.love_ref, to_yocto("100")).assert_success();
+    e.lock_free_seed(&users.farmer1, &booster_id, DEFAULT_MAX_LOCKING_DURATION_SEC, to_yocto("100")).assert_success();
+    e.mft_mint(&inner_id, &users.farmer2, to_yocto("10"));
+    e.mft_storage_deposit(&token_id, &e.farming_contract.user_account);
+    e.mft_stake_free_seed(&users.farmer2, &token_id, to_yocto("10")).assert_success();
+
+    // locked booster lends its power rather than its amount
+    let power: u128 = e.get_farmer_seed(&users.farmer1, &booster_id)["x_locked_amount"].as_str().unwrap().parse().unwrap();
+    assert!(power > to_yocto("100"));
+
+    // error scene 
+    // 1 : E210_EXCEED_DELEGATABLE_AMOUNT
+    assert_err!(
+        e.delegate_booster(&users.farmer1, &booster_id, &users.farmer2, power + 1),
+        E210_EXCEED_DELEGATABLE_AMOUNT
+    );
+
+    // success
+    e.delegate_booster(&users.farmer1, &booster_id, &users.farmer2, power).assert_success();
+    assert_eq!(e.list_farmer_booster_received(&users.farmer2).get(&booster_id).unwrap().0, power);
+
+    // 2 : E210_EXCEED_DELEGATABLE_AMOUNT, lent power can not be unlocked
+    e.skip_time(DEFAULT_MAX_LOCKING_DURATION_SEC);
+    assert_err!(
+        e.unlock_and_withdraw_seed(&users.farmer1, &booster_id, to_yocto("1"), 0),
+        E210_EXCEED_DELEGATABLE_AMOUNT
+    );
+
+    // revoking gives back exactly the lent power
+    e.revoke_booster_delegation(&users.farmer1, &booster_id, &users.farmer2).assert_success();
+    assert!(e.list_farmer_booster_received(&users.farmer2).is_empty());
+    e.unlock_and_withdraw_seed(&users.farmer1, &booster_id, to_yocto("100"), to_yocto("100")).assert_success();
+}
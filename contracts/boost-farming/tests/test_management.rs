@@ -55,6 +55,55 @@ fn test_modify_daily_reward(){
     assert_farm_info(e.get_farm(&farm_id), &farm_id, &tokens.nref, to_sec(e.current_time()), to_yocto("2"));
 }
 
+#[test]
+fn test_modify_farm_pause_when_empty(){
+    let e = init_env();
+    let users = Users::init(&e);
+    let tokens = Tokens::init(&e);
+
+    let inner_id = "0".to_string();
+    let seed_id = e.mft_seed_id(&inner_id);
+    let farm_id = format!("{}#{}", seed_id, 0);
+
+    e.create_seed(&e.owner, &seed_id, TOKEN_DECIMALS as u32, None, None).assert_success();
+    let start_at = e.current_time();
+    e.create_farm(&e.owner, &seed_id, &tokens.nref, to_sec(start_at), to_yocto("10")).assert_success();
+    e.ft_mint(&tokens.nref, &users.operator, to_yocto("100"));
+    e.deposit_reward(&tokens.nref, &users.operator, to_yocto("100"), &farm_id).assert_success();
+
+    // error scene 
+    // 1 : Requires attached deposit of exactly 1 yoctoNEAR
+    assert_err!(
+        e.modify_farm_pause_when_empty(&users.bob, &farm_id, true, 0),
+        "Requires attached deposit of exactly 1 yoctoNEAR"
+    );
+
+    // 2 : E002_NOT_ALLOWED  
+    assert_err!(
+        e.modify_farm_pause_when_empty(&users.bob, &farm_id, true, 1),
+        E002_NOT_ALLOWED
+    );
+
+    e.extend_operators(&e.owner, vec![&users.bob], 1).assert_success();
+
+    // 3 : E401_FARM_NOT_EXIST
+    assert_err!(
+        e.modify_farm_pause_when_empty(&users.bob, &format!("{}#{}", seed_id, 1), true, 1),
+        E401_FARM_NOT_EXIST
+    );
+
+    // success
+    e.modify_farm_pause_when_empty(&users.bob, &farm_id, true, 1).assert_success();
+    assert!(e.get_farm(&farm_id).terms.options.pause_when_empty);
+    e.skip_time(to_sec(NANOS_PER_DAY));
+    assert_farm_detail(e.get_farm(&farm_id), to_yocto("100"), e.current_time(), 0, 0, 0, 0, Some(FarmStatus::Running));
+
+    // reward flows to beneficiary again once the flag is cleared
+    e.modify_farm_pause_when_empty(&users.bob, &farm_id, false, 1).assert_success();
+    e.skip_time(to_sec(NANOS_PER_DAY));
+    assert_farm_detail(e.get_farm(&farm_id), to_yocto("100"), e.current_time(), to_yocto("10"), 0, to_yocto("10"), 0, Some(FarmStatus::Running));
+}
+
 #[test]
 fn test_modify_locking_policy(){
     let e = init_env();
@@ -115,6 +164,77 @@ fn test_modify_max_farm_num_per_seed(){
     assert_eq!(e.get_config().max_num_farms_per_seed, 20);
 }
 
+#[test]
+fn test_modify_farm_checkpoint_policy(){
+    let e = init_env();
+    let users = Users::init(&e);
+    let tokens = Tokens::init(&e);
+
+    let inner_id = "0".to_string();
+    let token_id = format!(":{}", inner_id);
+    let seed_id = e.mft_seed_id(&inner_id);
+    let farm_id = format!("{}#{}", seed_id, 0);
+    let cancelled_farm_id = format!("{}#{}", seed_id, 1);
+
+    // error scene 
+    // 1 : Requires attached deposit of exactly 1 yoctoNEAR
+    assert_err!(
+        e.modify_farm_checkpoint_policy(&e.owner, 3600, 2, 0),
+        "Requires attached deposit of exactly 1 yoctoNEAR"
+    );
+
+    // 2 : E002_NOT_ALLOWED
+    assert_err!(
+        e.modify_farm_checkpoint_policy(&users.bob, 3600, 2, 1),
+        E002_NOT_ALLOWED
+    );
+
+    // 3 : E213_INVALID_CHECKPOINT_NUM
+    assert_err!(
+        e.modify_farm_checkpoint_policy(&e.owner, 3600, 0, 1),
+        E213_INVALID_CHECKPOINT_NUM
+    );
+    assert_err!(
+        e.modify_farm_checkpoint_policy(&e.owner, 3600, MAX_NUM_FARM_CHECKPOINTS + 1, 1),
+        E213_INVALID_CHECKPOINT_NUM
+    );
+
+    // success
+    e.modify_farm_checkpoint_policy(&e.owner, 3600, 2, 1).assert_success();
+    assert_eq!(e.get_config().farm_checkpoint_interval_sec, 3600);
+    assert_eq!(e.get_config().max_num_farm_checkpoints, 2);
+
+    e.create_seed(&e.owner, &seed_id, TOKEN_DECIMALS as u32, None, None).assert_success();
+    e.create_farm(&e.owner, &seed_id, &tokens.nref, to_sec(e.current_time()), to_yocto("10")).assert_success();
+    e.ft_mint(&tokens.nref, &users.operator, to_yocto("100"));
+    e.deposit_reward(&tokens.nref, &users.operator, to_yocto("100"), &farm_id).assert_success();
+    assert_eq!(e.list_farm_checkpoints(&farm_id).len(), 1);
+
+    e.mft_mint(&inner_id, &users.farmer1, to_yocto("100"));
+    e.storage_deposit_self_to_farming(&users.farmer1).assert_success();
+    e.mft_storage_deposit(&token_id, &e.farming_contract.user_account);
+    for _ in 0..2 {
+        e.skip_time(3600);
+        e.mft_stake_free_seed(&users.farmer1, &token_id, to_yocto("50")).assert_success();
+    }
+    // oldest one is overwritten, the rest stays in time order
+    let checkpoints = e.list_farm_checkpoints(&farm_id);
+    assert_eq!(checkpoints.len(), 2);
+    let ts0: u64 = checkpoints[0]["timestamp"].as_str().unwrap().parse().unwrap();
+    let ts1: u64 = checkpoints[1]["timestamp"].as_str().unwrap().parse().unwrap();
+    assert!(ts0 + to_nano(3600) <= ts1);
+    assert_eq!(checkpoints[1]["total_seed_power"], to_yocto("100").to_string());
+
+    // checkpoints are dropped with the farm
+    e.create_farm(&e.owner, &seed_id, &tokens.nref, to_sec(e.current_time()), to_yocto("10")).assert_success();
+    assert_eq!(e.list_farm_checkpoints(&cancelled_farm_id).len(), 1);
+    e.cancel_farm(&e.owner, &cancelled_farm_id).assert_success();
+    assert!(e.list_farm_checkpoints(&cancelled_farm_id).is_empty());
+
+    e.remove_farm_from_seed(&e.owner, &farm_id).assert_success();
+    assert!(e.list_farm_checkpoints(&farm_id).is_empty());
+}
+
 #[test]
 fn test_modify_default_slash_rate(){
     let e = init_env();
@@ -488,6 +608,7 @@ fn test_withdraw_from_beneficiary_account(){
     assert_seed(e.get_seed(&seed_id), &seed_id, TOKEN_DECIMALS as u32, 1, 0, 0, MIN_SEED_DEPOSIT, DEFAULT_SEED_SLASH_RATE, DEFAULT_SEED_MIN_LOCKING_DURATION_SEC);
     assert_farm_info(e.get_farm(&farm_id), &farm_id, &tokens.nref, to_sec(start_at), to_yocto("10"));
     assert_farm_detail(e.get_farm(&farm_id), 0, start_at, 0, 0, 0, 0, Some(FarmStatus::Created));
+    e.set_farm_refund_account(&users.bob, &farm_id, Some(&e.owner), 1).assert_success();
 
 
     println!("> deposit_reward at : {}", e.current_time());
@@ -586,6 +707,7 @@ fn test_withdraw_from_outdated_beneficiary_account(){
     assert_seed(e.get_seed(&seed_id), &seed_id, TOKEN_DECIMALS as u32, 1, 0, 0, MIN_SEED_DEPOSIT, DEFAULT_SEED_SLASH_RATE, DEFAULT_SEED_MIN_LOCKING_DURATION_SEC);
     assert_farm_info(e.get_farm(&farm_id), &farm_id, &tokens.nref, to_sec(start_at), to_yocto("10"));
     assert_farm_detail(e.get_farm(&farm_id), 0, start_at, 0, 0, 0, 0, Some(FarmStatus::Created));
+    e.set_farm_refund_account(&users.bob, &farm_id, Some(&e.owner), 1).assert_success();
 
 
     println!("> deposit_reward at : {}", e.current_time());
@@ -675,6 +797,7 @@ fn test_withdraw_from_undistributed_reward(){
     assert_seed(e.get_seed(&seed_id), &seed_id, TOKEN_DECIMALS as u32, 1, 0, 0, MIN_SEED_DEPOSIT, DEFAULT_SEED_SLASH_RATE, DEFAULT_SEED_MIN_LOCKING_DURATION_SEC);
     assert_farm_info(e.get_farm(&farm_id), &farm_id, &tokens.nref, to_sec(start_at), to_yocto("10"));
     assert_farm_detail(e.get_farm(&farm_id), 0, start_at, 0, 0, 0, 0, Some(FarmStatus::Created));
+    e.set_farm_refund_account(&users.bob, &farm_id, Some(&e.owner), 1).assert_success();
 
 
     println!("> deposit_reward at : {}", e.current_time());
@@ -706,4 +829,161 @@ fn test_withdraw_from_undistributed_reward(){
     e.withdraw_from_undistributed_reward(&users.bob, &farm_id, 0, 1);
     assert_eq!(e.ft_balance_of(&tokens.nref,&e.owner), to_yocto("90"));
     assert_farm_detail(e.get_farm(&farm_id), to_yocto("10"), e.current_time(), to_yocto("10"), 0, to_yocto("10"), 0, Some(FarmStatus::Ended));    
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_refund_to_depositors(){
+    let e = init_env();
+    let users = Users::init(&e);
+    let tokens = Tokens::init(&e);
+
+    let inner_id = "0".to_string();
+    let seed_id = e.mft_seed_id(&inner_id);
+    let farm_id = format!("{}#{}", seed_id, 0);
+
+    e.create_seed(&e.owner, &seed_id, TOKEN_DECIMALS as u32, None, None).assert_success();
+    e.extend_operators(&e.owner, vec![&users.bob], 1).assert_success();
+
+    // error scene 
+    // 1 : Requires attached deposit of exactly 1 yoctoNEAR
+    assert_err!(
+        e.set_farm_refund_account(&users.bob, &farm_id, Some(&users.alice), 0),
+        "Requires attached deposit of exactly 1 yoctoNEAR"
+    );
+
+    // 2 : E002_NOT_ALLOWED
+    assert_err!(
+        e.set_farm_refund_account(&users.alice, &farm_id, Some(&users.alice), 1),
+        E002_NOT_ALLOWED
+    );
+
+    // 3 : E401_FARM_NOT_EXIST
+    assert_err!(
+        e.set_farm_refund_account(&users.bob, &farm_id, Some(&users.alice), 1),
+        E401_FARM_NOT_EXIST
+    );
+
+    let start_at = e.current_time();
+    e.create_farm(&e.owner, &seed_id, &tokens.nref, to_sec(start_at), to_yocto("10")).assert_success();
+
+    e.ft_mint(&tokens.nref, &users.operator, to_yocto("300"));
+    e.ft_mint(&tokens.nref, &users.farmer1, to_yocto("100"));
+    e.deposit_reward(&tokens.nref, &users.operator, to_yocto("300"), &farm_id).assert_success();
+    e.deposit_reward(&tokens.nref, &users.farmer1, to_yocto("100"), &farm_id).assert_success();
+    let refund_info = e.get_farm_refund_info(&farm_id);
+    assert_eq!(refund_info["depositors"][users.operator.account_id().to_string()], to_yocto("300").to_string());
+    assert_eq!(refund_info["depositors"][users.farmer1.account_id().to_string()], to_yocto("100").to_string());
+    assert!(refund_info["refund_account"].is_null());
+
+    // leftovers go back to depositors in proportion
+    e.withdraw_from_undistributed_reward(&users.bob, &farm_id, to_yocto("40"), 1).assert_success();
+    assert_eq!(e.ft_balance_of(&tokens.nref, &users.operator), to_yocto("30"));
+    assert_eq!(e.ft_balance_of(&tokens.nref, &users.farmer1), to_yocto("10"));
+    assert_farm_detail(e.get_farm(&farm_id), to_yocto("360"), start_at, 0, 0, 0, 0, Some(FarmStatus::Created));
+
+    // leftovers go to the configured refund account
+    e.ft_storage_deposit(&users.alice, &tokens.nref);
+    e.set_farm_refund_account(&users.bob, &farm_id, Some(&users.alice), 1).assert_success();
+    e.withdraw_from_undistributed_reward(&users.bob, &farm_id, to_yocto("60"), 1).assert_success();
+    assert_eq!(e.ft_balance_of(&tokens.nref, &users.alice), to_yocto("60"));
+    assert_eq!(e.ft_balance_of(&tokens.nref, &users.operator), to_yocto("30"));
+    assert_eq!(e.ft_balance_of(&tokens.nref, &users.farmer1), to_yocto("10"));
+    assert_farm_detail(e.get_farm(&farm_id), to_yocto("300"), start_at, 0, 0, 0, 0, Some(FarmStatus::Created));
+}
+
+#[test]
+fn test_refund_depositor_limits(){
+    let e = init_env();
+    let users = Users::init(&e);
+    let tokens = Tokens::init(&e);
+
+    let inner_id = "0".to_string();
+    let seed_id = e.mft_seed_id(&inner_id);
+    let farm_id = format!("{}#{}", seed_id, 0);
+
+    e.create_seed(&e.owner, &seed_id, TOKEN_DECIMALS as u32, None, None).assert_success();
+    let start_at = e.current_time();
+    e.create_farm(&e.owner, &seed_id, &tokens.nref, to_sec(start_at), to_yocto("10")).assert_success();
+    e.ft_mint(&tokens.nref, &users.alice, to_yocto("100"));
+
+    let depositors: Vec<UserAccount> = (0..MAX_NUM_DEPOSITORS_PER_FARM)
+        .map(|i| e.near.create_user(account_id(&format!("depositor{}.near", i)), to_yocto("100")))
+        .collect();
+    for depositor in depositors.iter() {
+        e.ft_mint(&tokens.nref, depositor, to_yocto("20"));
+        e.deposit_reward(&tokens.nref, depositor, to_yocto("10"), &farm_id).assert_success();
+    }
+
+    // depositors beyond the limit are accepted with any amount, but not recorded
+    e.deposit_reward(&tokens.nref, &users.alice, 1, &farm_id).assert_success();
+    let refund_info = e.get_farm_refund_info(&farm_id);
+    assert_eq!(refund_info["depositors"].as_object().unwrap().len(), MAX_NUM_DEPOSITORS_PER_FARM);
+    assert!(refund_info["depositors"].get(users.alice.account_id().to_string()).is_none());
+    assert_eq!(e.get_farm(&farm_id).total_reward, to_yocto("80") + 1);
+
+    // all depositors are refunded within one call, and their records shrink by what was paid
+    e.extend_operators(&e.owner, vec![&users.bob], 1).assert_success();
+    e.withdraw_from_undistributed_reward(&users.bob, &farm_id, to_yocto("40"), 1).assert_success();
+    for depositor in depositors.iter() {
+        assert_eq!(e.ft_balance_of(&tokens.nref, depositor), to_yocto("15"));
+        assert_eq!(e.get_farm_refund_info(&farm_id)["depositors"][depositor.account_id().to_string()], to_yocto("5").to_string());
+    }
+
+    // existing depositors can still top up with any amount
+    e.deposit_reward(&tokens.nref, &depositors[0], 1, &farm_id).assert_success();
+    assert_eq!(e.get_farm_refund_info(&farm_id)["depositors"][depositors[0].account_id().to_string()], (to_yocto("5") + 1).to_string());
+}
+
+#[test]
+fn test_farm_beneficiary(){
+    let e = init_env();
+    let users = Users::init(&e);
+    let tokens = Tokens::init(&e);
+
+    let inner_id = "0".to_string();
+    let seed_id = e.mft_seed_id(&inner_id);
+    let farm_id = format!("{}#{}", seed_id, 0);
+    let paused_farm_id = format!("{}#{}", seed_id, 1);
+
+    e.create_seed(&e.owner, &seed_id, TOKEN_DECIMALS as u32, None, None).assert_success();
+
+    let start_at = e.current_time();
+    e.create_farm_with_terms(&e.owner, &seed_id, FarmTerms{
+        reward_token: tokens.nref.account_id(),
+        start_at: to_sec(start_at),
+        daily_reward: to_yocto("10"),
+        options: FarmOptions { beneficiary_id: Some(users.alice.account_id()), pause_when_empty: false },
+    }).assert_success();
+    e.create_farm_with_terms(&e.owner, &seed_id, FarmTerms{
+        reward_token: tokens.nref.account_id(),
+        start_at: to_sec(start_at),
+        daily_reward: to_yocto("10"),
+        options: FarmOptions { beneficiary_id: None, pause_when_empty: true },
+    }).assert_success();
+
+    e.ft_mint(&tokens.nref, &users.operator, to_yocto("200"));
+    e.deposit_reward(&tokens.nref, &users.operator, to_yocto("100"), &farm_id).assert_success();
+    e.deposit_reward(&tokens.nref, &users.operator, to_yocto("100"), &paused_farm_id).assert_success();
+
+    e.skip_time(to_sec(NANOS_PER_DAY));
+    // reward of an empty seed goes to beneficiary, or pauses
+    assert_farm_detail(e.get_farm(&farm_id), to_yocto("100"), e.current_time(), to_yocto("10"), 0, to_yocto("10"), 0, Some(FarmStatus::Running));
+    assert_farm_detail(e.get_farm(&paused_farm_id), to_yocto("100"), e.current_time(), 0, 0, 0, 0, Some(FarmStatus::Running));
+
+    // error scene 
+    // 1 : E002_NOT_ALLOWED
+    assert_err!(
+        e.withdraw_from_beneficiary_account(&users.bob, &farm_id, 1),
+        E002_NOT_ALLOWED
+    );
+    assert_err!(
+        e.withdraw_from_beneficiary_account(&users.alice, &paused_farm_id, 1),
+        E002_NOT_ALLOWED
+    );
+
+    // success
+    e.ft_storage_deposit(&users.alice, &tokens.nref);
+    e.withdraw_from_beneficiary_account(&users.alice, &farm_id, 1).assert_success();
+    assert_eq!(e.ft_balance_of(&tokens.nref, &users.alice), to_yocto("10"));
+    assert_farm_detail(e.get_farm(&farm_id), to_yocto("100"), e.current_time(), to_yocto("10"), 0, to_yocto("10"), to_yocto("10"), Some(FarmStatus::Running));
+}
@@ -0,0 +1,103 @@
+mod setup;
+use crate::setup::*;
+use near_sdk::json_types::U128;
+
+#[test]
+fn test_nft_seed(){
+    let e = init_env();
+    let users = Users::init(&e);
+    let nft = init_nft(&e, &account_id("nft.near"));
+
+    let seed_id = e.nft_seed_id(&nft);
+    let token_a = "a".to_string();
+    let token_b = "b".to_string();
+
+    println!("> create_seed at : {}", e.current_time());
+    e.create_seed(&e.owner, &seed_id, TOKEN_DECIMALS as u32, Some(U128(1)), None).assert_success();
+    e.storage_deposit_self_to_farming(&users.farmer1).assert_success();
+    e.nft_mint(&nft, &token_a, &users.farmer1, 100);
+    e.nft_mint(&nft, &token_b, &users.farmer1, 50);
+
+    // error scene
+    // 1 : E603_NFT_VALUATION_NOT_SET, nft is given back by nft_resolve_transfer
+    assert_err!(
+        e.nft_stake_free_seed(&nft, &users.farmer1, &token_a, &seed_id),
+        E603_NFT_VALUATION_NOT_SET
+    );
+    assert_eq!(e.nft_owner_of(&nft, &token_a), users.farmer1.account_id());
+
+    // 2 : E308_INVALID_SEED_ID
+    assert_err!(
+        e.modify_seed_nft_valuation(&e.owner, &format!("{}@0", e.multi_fungible_token_contract.user_account.account_id), Some(NftValuation::Callback { method: "get_liquidity".to_string() }), 1),
+        E308_INVALID_SEED_ID
+    );
+
+    // success with Callback valuation
+    println!("> farmer1 stake nft valued by callback at : {}", e.current_time());
+    e.modify_seed_nft_valuation(&e.owner, &seed_id, Some(NftValuation::Callback { method: "get_liquidity".to_string() }), 1).assert_success();
+    e.nft_stake_free_seed(&nft, &users.farmer1, &token_a, &seed_id).assert_success();
+    assert_eq!(e.nft_owner_of(&nft, &token_a), e.farming_contract.account_id());
+    let farmer_seed = e.get_farmer_seed(&users.farmer1, &seed_id);
+    assert_user_seed_info(farmer_seed.clone(), 100, 0, 0, 0, 0);
+    assert_eq!(farmer_seed["nft_ids"][&token_a], "100");
+
+    // success with MetadataExtra valuation
+    println!("> farmer1 stake nft valued by metadata extra at : {}", e.current_time());
+    e.modify_seed_nft_valuation(&e.owner, &seed_id, Some(NftValuation::MetadataExtra { field: "liquidity".to_string() }), 1).assert_success();
+    e.nft_stake_free_seed(&nft, &users.farmer1, &token_b, &seed_id).assert_success();
+    assert_eq!(e.nft_owner_of(&nft, &token_b), e.farming_contract.account_id());
+    let farmer_seed = e.get_farmer_seed(&users.farmer1, &seed_id);
+    assert_user_seed_info(farmer_seed.clone(), 150, 0, 0, 0, 0);
+    assert_eq!(farmer_seed["nft_ids"][&token_b], "50");
+    assert_eq!(e.get_seed(&seed_id)["total_seed_amount"], "150");
+
+    // 3 : E606_NFT_SEED_OPERATION
+    assert_err!(
+        e.unlock_and_withdraw_seed(&users.farmer1, &seed_id, 0, 100),
+        E606_NFT_SEED_OPERATION
+    );
+
+    // 4 : E605_NFT_NOT_STAKED
+    assert_err!(
+        e.withdraw_nft_seed(&users.farmer1, &seed_id, &"c".to_string()),
+        E605_NFT_NOT_STAKED
+    );
+
+    // withdraw success
+    println!("> farmer1 withdraw_nft_seed at : {}", e.current_time());
+    e.withdraw_nft_seed(&users.farmer1, &seed_id, &token_a).assert_success();
+    assert_eq!(e.nft_owner_of(&nft, &token_a), users.farmer1.account_id());
+    assert_user_seed_info(e.get_farmer_seed(&users.farmer1, &seed_id), 50, 0, 0, 0, 0);
+    assert_eq!(e.get_seed(&seed_id)["total_seed_amount"], "50");
+
+    // withdraw with failed nft_transfer goes to lostfound
+    println!("> farmer1 withdraw_nft_seed into lostfound at : {}", e.current_time());
+    e.nft_set_transfer_paused(&nft, true);
+    e.withdraw_nft_seed(&users.farmer1, &seed_id, &token_b).assert_success();
+    assert_eq!(e.nft_owner_of(&nft, &token_b), e.farming_contract.account_id());
+    assert_eq!(e.get_seed(&seed_id)["total_seed_amount"], "0");
+    assert_eq!(e.list_nfts_lostfound(), vec![(seed_id.clone(), token_b.clone(), users.farmer1.account_id())]);
+
+    // 5 : E002_NOT_ALLOWED
+    assert_err!(
+        e.return_nft_lostfound(&users.farmer1, &seed_id, &token_b, 1),
+        E002_NOT_ALLOWED
+    );
+
+    // failed again, back to lostfound
+    e.return_nft_lostfound(&e.owner, &seed_id, &token_b, 1).assert_success();
+    assert_eq!(e.list_nfts_lostfound(), vec![(seed_id.clone(), token_b.clone(), users.farmer1.account_id())]);
+
+    // return success
+    println!("> owner return_nft_lostfound at : {}", e.current_time());
+    e.nft_set_transfer_paused(&nft, false);
+    e.return_nft_lostfound(&e.owner, &seed_id, &token_b, 1).assert_success();
+    assert_eq!(e.nft_owner_of(&nft, &token_b), users.farmer1.account_id());
+    assert!(e.list_nfts_lostfound().is_empty());
+
+    // 6 : E607_NFT_NOT_IN_LOSTFOUND
+    assert_err!(
+        e.return_nft_lostfound(&e.owner, &seed_id, &token_b, 1),
+        E607_NFT_NOT_IN_LOSTFOUND
+    );
+}
@@ -1,5 +1,7 @@
 mod setup;
 use crate::setup::*;
+use std::collections::HashMap;
+use near_sdk::json_types::U128;
 
 
 #[test]
@@ -108,3 +110,108 @@ fn test_operators(){
     e.remove_operators(&e.owner, vec![&users.bob], 1).assert_success();
     assert_eq!(e.get_metadata().operators, vec![]);
 }
+
+#[test]
+fn test_check_solvency(){
+    let e = init_env();
+    let users = Users::init(&e);
+    let tokens = Tokens::init(&e);
+
+    let inner_id = "0".to_string();
+    let token_id = format!(":{}", inner_id);
+    let seed_id = e.mft_seed_id(&inner_id);
+    let farm_id = format!("{}#{}", seed_id, 0);
+
+    e.create_seed(&e.owner, &seed_id, TOKEN_DECIMALS as u32, None, None).assert_success();
+    e.create_farm(&e.owner, &seed_id, &tokens.nref, to_sec(e.current_time()), to_yocto("10")).assert_success();
+    e.ft_mint(&tokens.nref, &users.operator, to_yocto("10000"));
+    e.deposit_reward(&tokens.nref, &users.operator, to_yocto("100"), &farm_id).assert_success();
+
+    e.storage_deposit(&users.bob, &users.bob, to_yocto("0.1")).assert_success();
+    e.mft_mint(&inner_id, &users.bob, to_yocto("100"));
+    e.mft_storage_deposit(&token_id, &e.farming_contract.user_account);
+    e.mft_stake_free_seed(&users.bob, &token_id, to_yocto("100")).assert_success();
+
+    // claimed reward still belongs to the contract until withdrawn
+    e.skip_time(to_sec(NANOS_PER_DAY));
+    e.claim_reward_by_seed(&users.bob, &seed_id).assert_success();
+
+    let liabilities = e.get_liabilities(None, None);
+    assert_eq!(liabilities.len(), 2);
+    assert_eq!(liabilities.get(&seed_id).unwrap().0, to_yocto("100"));
+    assert_eq!(liabilities.get(&tokens.nref.account_id().to_string()).unwrap().0, to_yocto("100"));
+
+    // pages of the walking list sum up to the totals
+    let first_page = e.get_liabilities(Some(0), Some(1));
+    let second_page = e.get_liabilities(Some(1), None);
+    let nref_key = tokens.nref.account_id().to_string();
+    assert_eq!(first_page.get(&seed_id).unwrap().0, to_yocto("100"));
+    assert!(second_page.get(&seed_id).is_none());
+    assert_eq!(
+        first_page.get(&nref_key).unwrap().0 + second_page.get(&nref_key).unwrap().0,
+        to_yocto("100")
+    );
+
+    // error scene
+    // 1 : Requires attached deposit of exactly 1 yoctoNEAR
+    assert_err!(
+        e.check_solvency(&e.owner, liabilities.clone(), 0),
+        "Requires attached deposit of exactly 1 yoctoNEAR"
+    );
+
+    // 2 : E002_NOT_ALLOWED
+    assert_err!(
+        e.check_solvency(&users.alice, liabilities.clone(), 1),
+        E002_NOT_ALLOWED
+    );
+
+    // 3 : E010_EXCEED_SOLVENCY_CHECK_NUM
+    let too_many: HashMap<String, U128> = (0..=MAX_NUM_SOLVENCY_CHECKS)
+        .map(|index| (format!("token{}.test", index), U128(0)))
+        .collect();
+    assert_err!(
+        e.check_solvency(&e.owner, too_many, 1),
+        E010_EXCEED_SOLVENCY_CHECK_NUM
+    );
+
+    let solvency_logs = |outcome: &ExecutionResult| {
+        outcome.promise_results().iter().flat_map(|result| {
+            result.as_ref().map_or(vec![], |result| {
+                result.logs().iter().filter(|log| log.contains("solvency_check")).cloned().collect()
+            })
+        }).collect::<Vec<String>>()
+    };
+
+    // balances match liabilities
+    let outcome = e.check_solvency(&e.owner, liabilities.clone(), 1);
+    outcome.assert_success();
+    let logs = solvency_logs(&outcome);
+    assert_eq!(logs.len(), 2);
+    assert!(logs.iter().all(|log| log.contains(r#""surplus":"0","insolvent":false"#)));
+
+    // extra reward token sent in directly is a surplus, not a shortfall
+    e.ft_mint(&tokens.nref, &e.farming_contract.user_account, to_yocto("1"));
+    let outcome = e.check_solvency(&e.owner, liabilities.clone(), 1);
+    outcome.assert_success();
+    let logs = solvency_logs(&outcome);
+    assert!(logs.iter().any(|log| log.contains(&format!(r#""surplus":"{}","insolvent":false"#, to_yocto("1")))));
+    assert!(logs.iter().all(|log| !log.contains(r#""insolvent":true"#)));
+
+    // balance less than liability is flagged
+    let mut short = HashMap::new();
+    short.insert(seed_id.clone(), U128(to_yocto("100") + 1));
+    let outcome = e.check_solvency(&e.owner, short, 1);
+    outcome.assert_success();
+    let logs = solvency_logs(&outcome);
+    assert_eq!(logs.len(), 1);
+    assert!(logs[0].contains(r#""surplus":"0","insolvent":true,"failed":false"#));
+
+    // balance query failure is reported as failed
+    let mut unknown = HashMap::new();
+    unknown.insert("nobody.test".to_string(), U128(1));
+    let outcome = e.check_solvency(&e.owner, unknown, 1);
+    let logs = solvency_logs(&outcome);
+    assert_eq!(logs.len(), 1);
+    assert!(logs[0].contains(r#""actual":null"#));
+    assert!(logs[0].contains(r#""failed":true"#));
+}
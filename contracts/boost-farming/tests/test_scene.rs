@@ -202,7 +202,7 @@ fn test_normal_seed_single_farm(){
     assert_eq!(e.get_metadata().farm_count.0, 0);
     assert_eq!(e.get_metadata().outdated_farm_count.0, 1);
 
-    assert_farm_detail(e.get_outdated_farm(&farm_id), to_yocto("200"), e.current_time(), to_yocto("200"), to_yocto("50"), to_yocto("150"), 0, None);
+    assert_farm_detail(e.get_outdated_farm(&farm_id), to_yocto("200"), e.current_time(), to_yocto("200"), to_yocto("50"), to_yocto("10"), 0, None);
     assert_eq!(format!("{:?}", e.get_outdated_farm(&farm_id)), format!("{:?}", e.list_outdated_farms()[0]));
     
 
@@ -0,0 +1,247 @@
+mod setup;
+use crate::setup::*;
+
+#[test]
+fn test_shadow_provider(){
+    let e = init_env();
+    let users = Users::init(&e);
+
+    let lending = &users.dude;
+    let seed_id = e.mft_seed_id(&"0".to_string());
+    let shadow_provider = ShadowProvider {
+        shadow_prefix: "shadow_lending-".to_string(),
+        rule: ShadowSeedRule::MftPool { token_id: e.multi_fungible_token_contract.user_account.account_id() },
+        allowed_seeds: vec![seed_id.clone()],
+    };
+
+    // error scene
+    // 1 : set with E002_NOT_ALLOWED
+    assert_err!(
+        e.set_shadow_provider(&users.alice, lending, shadow_provider.clone(), 1),
+        E002_NOT_ALLOWED
+    );
+
+    // 2 : set with E301_SEED_NOT_EXIST
+    assert_err!(
+        e.set_shadow_provider(&e.owner, lending, shadow_provider.clone(), 1),
+        E301_SEED_NOT_EXIST
+    );
+
+    e.create_seed(&e.owner, &seed_id, TOKEN_DECIMALS as u32, None, None).assert_success();
+    e.create_seed(&e.owner, &e.mft_seed_id(&"1".to_string()), TOKEN_DECIMALS as u32, None, None).assert_success();
+    e.storage_deposit_self_to_farming(&users.farmer1).assert_success();
+
+    // 3 : cast from unregistered provider with E002_NOT_ALLOWED
+    assert_err!(
+        e.on_cast_shadow(lending, &users.farmer1, "shadow_lending-0", to_yocto("1"), ""),
+        E002_NOT_ALLOWED
+    );
+
+    e.set_shadow_provider(&e.owner, lending, shadow_provider.clone(), 1).assert_success();
+    assert_eq!(e.get_shadow_provider(lending), Some(shadow_provider));
+
+    // 4 : cast with E701_INVALID_SHADOW_ID
+    assert_err!(
+        e.on_cast_shadow(lending, &users.farmer1, "shadow_ref_v1-0", to_yocto("1"), ""),
+        E701_INVALID_SHADOW_ID
+    );
+
+    // 5 : cast with E702_SHADOW_SEED_NOT_ALLOWED
+    assert_err!(
+        e.on_cast_shadow(lending, &users.farmer1, "shadow_lending-1", to_yocto("1"), ""),
+        E702_SHADOW_SEED_NOT_ALLOWED
+    );
+
+    // success
+    e.on_cast_shadow(lending, &users.farmer1, "shadow_lending-0", to_yocto("1"), "").assert_success();
+    assert_eq!(e.get_farmer_seed(&users.farmer1, &seed_id)["shadow_amount"], to_yocto("1").to_string());
+    let shadow_stakes = e.list_shadow_stakes();
+    assert_eq!(shadow_stakes.len(), 1);
+    assert_eq!(shadow_stakes[0].farmer_id, users.farmer1.account_id());
+    assert_eq!(shadow_stakes[0].seed_id, seed_id);
+    assert_eq!(shadow_stakes[0].shadow_amount.0, to_yocto("1"));
+    e.on_remove_shadow(lending, &users.farmer1, "shadow_lending-0", to_yocto("1"), "").assert_success();
+    assert!(e.get_farmer_seed(&users.farmer1, &seed_id).is_null());
+    assert!(e.list_shadow_stakes().is_empty());
+
+    // 6 : remove with E700_SHADOW_PROVIDER_NOT_EXIST
+    e.remove_shadow_provider(&e.owner, lending, 1).assert_success();
+    assert_eq!(e.get_shadow_provider(lending), None);
+    assert_err!(
+        e.remove_shadow_provider(&e.owner, lending, 1),
+        E700_SHADOW_PROVIDER_NOT_EXIST
+    );
+}
+
+#[test]
+fn test_shadow_lock(){
+    let e = init_env();
+    let users = Users::init(&e);
+
+    let lending = &users.dude;
+    let seed_id = e.mft_seed_id(&"0".to_string());
+    e.create_seed(&e.owner, &seed_id, TOKEN_DECIMALS as u32, None, Some(DEFAULT_SEED_MIN_LOCKING_DURATION_SEC)).assert_success();
+    e.storage_deposit_self_to_farming(&users.farmer1).assert_success();
+    e.set_shadow_provider(&e.owner, lending, ShadowProvider {
+        shadow_prefix: "shadow_lending-".to_string(),
+        rule: ShadowSeedRule::MftPool { token_id: e.multi_fungible_token_contract.user_account.account_id() },
+        allowed_seeds: vec![seed_id.clone()],
+    }, 1).assert_success();
+    let lock_msg = format!("{{\"Lock\":{{\"duration_sec\":{}}}}}", DEFAULT_MAX_LOCKING_DURATION_SEC);
+
+    // error scene
+    // 1 : cast with E500_INVALID_MSG
+    assert_err!(
+        e.on_cast_shadow(lending, &users.farmer1, "shadow_lending-0", to_yocto("100"), "Lock"),
+        E500_INVALID_MSG
+    );
+
+    // 2 : cast with E201_INVALID_DURATION
+    assert_err!(
+        e.on_cast_shadow(lending, &users.farmer1, "shadow_lending-0", to_yocto("100"), &format!("{{\"Lock\":{{\"duration_sec\":{}}}}}", DEFAULT_MAX_LOCKING_DURATION_SEC + 1)),
+        E201_INVALID_DURATION
+    );
+
+    // success
+    e.on_cast_shadow(lending, &users.farmer1, "shadow_lending-0", to_yocto("100"), &lock_msg).assert_success();
+    e.on_cast_shadow(lending, &users.farmer1, "shadow_lending-0", to_yocto("10"), "").assert_success();
+    let farmer_seed = e.get_farmer_seed(&users.farmer1, &seed_id);
+    assert_eq!(farmer_seed["shadow_amount"], to_yocto("10").to_string());
+    assert_eq!(farmer_seed["shadow_locked_amount"], to_yocto("100").to_string());
+    let x_locked_amount = to_yocto("100") * DEFAULT_MAX_LOCKING_REWARD_RATIO as u128 / MIN_LOCKING_REWARD_RATIO as u128;
+    assert_eq!(farmer_seed["shadow_x_locked_amount"], x_locked_amount.to_string());
+
+    // locked shadow counts in power and seed statistics
+    let seed = e.get_seed(&seed_id);
+    assert_eq!(seed["total_seed_amount"], to_yocto("110").to_string());
+    assert_eq!(seed["total_seed_power"], (to_yocto("10") + x_locked_amount).to_string());
+    assert_eq!(seed["farmer_count"], 1);
+    assert_eq!(seed["total_free_amount"], "0");
+    assert_eq!(seed["total_locked_amount"], to_yocto("100").to_string());
+    assert_eq!(seed["total_x_locked_amount"], x_locked_amount.to_string());
+    assert_eq!(seed["total_shadow_amount"], to_yocto("110").to_string());
+
+    // 3 : remove locked part with E305_STILL_IN_LOCK
+    assert_err!(
+        e.on_remove_shadow(lending, &users.farmer1, "shadow_lending-0", to_yocto("11"), ""),
+        E305_STILL_IN_LOCK
+    );
+
+    // free shadow can still be removed
+    e.on_remove_shadow(lending, &users.farmer1, "shadow_lending-0", to_yocto("10"), "").assert_success();
+
+    e.skip_time(DEFAULT_MAX_LOCKING_DURATION_SEC);
+    e.on_remove_shadow(lending, &users.farmer1, "shadow_lending-0", to_yocto("100"), "").assert_success();
+    assert!(e.get_farmer_seed(&users.farmer1, &seed_id).is_null());
+    let seed = e.get_seed(&seed_id);
+    assert_eq!(seed["total_seed_power"], "0");
+    assert_eq!(seed["farmer_count"], 0);
+    assert_eq!(seed["total_locked_amount"], "0");
+    assert_eq!(seed["total_x_locked_amount"], "0");
+    assert_eq!(seed["total_shadow_amount"], "0");
+}
+
+#[test]
+fn test_shadow_per_provider(){
+    let e = init_env();
+    let users = Users::init(&e);
+
+    let lending = &users.dude;
+    let vault = &users.charlie;
+    let seed_id = e.mft_seed_id(&"0".to_string());
+    e.create_seed(&e.owner, &seed_id, TOKEN_DECIMALS as u32, None, None).assert_success();
+    e.storage_deposit_self_to_farming(&users.farmer1).assert_success();
+    for (provider, prefix) in [(lending, "shadow_lending-"), (vault, "shadow_vault-")] {
+        e.set_shadow_provider(&e.owner, provider, ShadowProvider {
+            shadow_prefix: prefix.to_string(),
+            rule: ShadowSeedRule::MftPool { token_id: e.multi_fungible_token_contract.user_account.account_id() },
+            allowed_seeds: vec![seed_id.clone()],
+        }, 1).assert_success();
+    }
+
+    e.on_cast_shadow(lending, &users.farmer1, "shadow_lending-0", to_yocto("10"), "").assert_success();
+    e.on_cast_shadow(vault, &users.farmer1, "shadow_vault-0", to_yocto("5"), "").assert_success();
+    let farmer_seed = e.get_farmer_seed(&users.farmer1, &seed_id);
+    assert_eq!(farmer_seed["shadow_amount"], to_yocto("15").to_string());
+    assert_eq!(farmer_seed["shadow_by_provider"][lending.account_id().to_string()], to_yocto("10").to_string());
+    assert_eq!(farmer_seed["shadow_by_provider"][vault.account_id().to_string()], to_yocto("5").to_string());
+
+    // error scene
+    // 1 : remove shadow cast by another provider with E704_SHADOW_NOT_CAST_BY_PROVIDER
+    assert_err!(
+        e.on_remove_shadow(vault, &users.farmer1, "shadow_vault-0", to_yocto("6"), ""),
+        E704_SHADOW_NOT_CAST_BY_PROVIDER
+    );
+
+    // 2 : remove_orphan_shadow with Requires attached deposit of exactly 1 yoctoNEAR
+    assert_err!(
+        e.remove_orphan_shadow(&users.farmer1, &users.farmer1, lending, &seed_id, 0),
+        "Requires attached deposit of exactly 1 yoctoNEAR"
+    );
+
+    // 3 : remove_orphan_shadow with E705_SHADOW_PROVIDER_STILL_ACTIVE
+    assert_err!(
+        e.remove_orphan_shadow(&users.farmer1, &users.farmer1, lending, &seed_id, 1),
+        E705_SHADOW_PROVIDER_STILL_ACTIVE
+    );
+
+    e.remove_shadow_provider(&e.owner, lending, 1).assert_success();
+
+    // 4 : remove_orphan_shadow with E002_NOT_ALLOWED
+    assert_err!(
+        e.remove_orphan_shadow(&users.alice, &users.farmer1, lending, &seed_id, 1),
+        E002_NOT_ALLOWED
+    );
+
+    // stale shadow of the removed provider is unwound, the other provider's stays
+    e.remove_orphan_shadow(&users.farmer1, &users.farmer1, lending, &seed_id, 1).assert_success();
+    let farmer_seed = e.get_farmer_seed(&users.farmer1, &seed_id);
+    assert_eq!(farmer_seed["shadow_amount"], to_yocto("5").to_string());
+    assert!(farmer_seed["shadow_by_provider"].get(lending.account_id().to_string()).is_none());
+
+    // 5 : nothing left to unwind with E704_SHADOW_NOT_CAST_BY_PROVIDER
+    assert_err!(
+        e.remove_orphan_shadow(&e.owner, &users.farmer1, lending, &seed_id, 1),
+        E704_SHADOW_NOT_CAST_BY_PROVIDER
+    );
+
+    e.on_remove_shadow(vault, &users.farmer1, "shadow_vault-0", to_yocto("5"), "").assert_success();
+    assert!(e.get_farmer_seed(&users.farmer1, &seed_id).is_null());
+    assert_eq!(e.storage_unregister(&users.farmer1, 1).unwrap_json::<bool>(), true);
+}
+
+#[test]
+fn test_sync_shadow_amount(){
+    let e = init_env();
+    let users = Users::init(&e);
+
+    let seed_id = e.mft_seed_id(&"0".to_string());
+    e.create_seed(&e.owner, &seed_id, TOKEN_DECIMALS as u32, None, None).assert_success();
+    e.create_seed(&e.owner, &"ref_exchange@0".to_string(), TOKEN_DECIMALS as u32, None, None).assert_success();
+    e.storage_deposit_self_to_farming(&users.farmer1).assert_success();
+
+    // error scene
+    // 1 : Requires attached deposit of exactly 1 yoctoNEAR
+    assert_err!(
+        e.sync_shadow_amount(&e.owner, &users.farmer1, &"ref_exchange@0".to_string(), 0),
+        "Requires attached deposit of exactly 1 yoctoNEAR"
+    );
+
+    // 2 : E002_NOT_ALLOWED
+    assert_err!(
+        e.sync_shadow_amount(&users.alice, &users.farmer1, &"ref_exchange@0".to_string(), 1),
+        E002_NOT_ALLOWED
+    );
+
+    // 3 : E308_INVALID_SEED_ID for seed not from ref_exchange
+    assert_err!(
+        e.sync_shadow_amount(&e.owner, &users.farmer1, &seed_id, 1),
+        E308_INVALID_SEED_ID
+    );
+
+    // 4 : E100_ACC_NOT_REGISTERED
+    assert_err!(
+        e.sync_shadow_amount(&e.owner, &users.farmer2, &"ref_exchange@0".to_string(), 1),
+        E100_ACC_NOT_REGISTERED
+    );
+}
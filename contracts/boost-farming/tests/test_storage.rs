@@ -29,8 +29,8 @@ fn test_storage(){
     let user_balance_before = users.alice.account().unwrap().amount;
     assert_eq!(e.storage_unregister(&users.alice, 1).unwrap_json::<bool>(), true);
     let user_balance_after = users.alice.account().unwrap().amount;
-    assert!(user_balance_after - user_balance_before > to_yocto("0.09"));
-    assert!(user_balance_after - user_balance_before < to_yocto("0.1"));
+    assert!(user_balance_after - user_balance_before > to_yocto("0.19"));
+    assert!(user_balance_after - user_balance_before < to_yocto("0.2"));
     assert_eq!(e.get_metadata().farmer_count.0, 1);
 
     // repeat unregister
@@ -44,10 +44,10 @@ fn test_storage(){
         "Requires attached deposit of exactly 1 yoctoNEAR"
     );
 
-    // 2 : storage_withdraw with E101_INSUFFICIENT_BALANCE
+    // 2 : storage_withdraw with E100_ACC_NOT_REGISTERED
     assert_err!(
         e.storage_withdraw(&users.alice, 1),
-        E006_NOT_IMPLEMENTED
+        E100_ACC_NOT_REGISTERED
     );
 
     // 3 : storage_unregister with storage_withdraw with Requires attached deposit of exactly 1 yoctoNEAR
@@ -91,4 +91,190 @@ fn test_storage(){
         e.storage_unregister(&users.bob, 1),
         E103_STILL_HAS_REWARD
     );
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_storage_withdraw(){
+    let e = init_env();
+    let users = Users::init(&e);
+
+    e.storage_deposit(&users.alice, &users.alice, to_yocto("0.5")).assert_success();
+    let storage_balance = e.storage_balance_of(&users.alice);
+    assert_eq!(storage_balance["total"], to_yocto("0.5").to_string());
+    assert_eq!(storage_balance["available"], to_yocto("0.4").to_string());
+
+    // top up
+    e.storage_deposit(&users.alice, &users.alice, to_yocto("0.1")).assert_success();
+    assert_eq!(e.storage_balance_of(&users.alice)["available"], to_yocto("0.5").to_string());
+
+    // error scene
+    // 1 : E101_INSUFFICIENT_BALANCE
+    assert_err!(
+        e.storage_withdraw_amount(&users.alice, Some(to_yocto("0.6")), 1),
+        E101_INSUFFICIENT_BALANCE
+    );
+
+    // success
+    let user_balance_before = users.alice.account().unwrap().amount;
+    e.storage_withdraw_amount(&users.alice, Some(to_yocto("0.2")), 1).assert_success();
+    assert_eq!(e.storage_balance_of(&users.alice)["available"], to_yocto("0.3").to_string());
+    e.storage_withdraw(&users.alice, 1).assert_success();
+    let user_balance_after = users.alice.account().unwrap().amount;
+    assert!(user_balance_after - user_balance_before > to_yocto("0.49"));
+    assert!(user_balance_after - user_balance_before < to_yocto("0.5"));
+    let storage_balance = e.storage_balance_of(&users.alice);
+    assert_eq!(storage_balance["total"], to_yocto("0.1").to_string());
+    assert_eq!(storage_balance["available"], "0");
+
+    // registration fee can not be withdrawn
+    e.storage_withdraw(&users.alice, 1).assert_success();
+    assert_eq!(e.storage_balance_of(&users.alice)["total"], to_yocto("0.1").to_string());
+}
+
+#[test]
+fn test_auto_register_farmer(){
+    let e = init_env();
+    let users = Users::init(&e);
+
+    let inner_id = "0".to_string();
+    let token_id = format!(":{}", inner_id);
+    let seed_id = e.mft_seed_id(&inner_id);
+
+    e.create_seed(&e.owner, &seed_id, TOKEN_DECIMALS as u32, None, None).assert_success();
+    e.mft_storage_deposit(&token_id, &e.farming_contract.user_account);
+    e.mft_mint(&inner_id, &users.alice, to_yocto("100"));
+    e.mft_mint(&inner_id, &users.bob, to_yocto("100"));
+
+    // error scene
+    // 1 : E100_ACC_NOT_REGISTERED, auto registration is off
+    assert_err!(
+        e.mft_stake_free_seed(&users.alice, &token_id, to_yocto("10")),
+        E100_ACC_NOT_REGISTERED
+    );
+
+    // 2 : E100_ACC_NOT_REGISTERED, neither sponsor pool nor register fee can pay
+    e.modify_auto_register_farmer(&e.owner, true, 1).assert_success();
+    assert_err!(
+        e.mft_stake_free_seed(&users.alice, &token_id, to_yocto("10")),
+        E100_ACC_NOT_REGISTERED
+    );
+
+    // paid by sponsor pool
+    e.deposit_farmer_sponsor_pool(&users.operator, to_yocto("0.1")).assert_success();
+    assert_eq!(e.get_metadata().farmer_sponsor_pool.0, to_yocto("0.1"));
+    e.mft_stake_free_seed(&users.alice, &token_id, to_yocto("10")).assert_success();
+    assert_eq!(e.get_metadata().farmer_count.0, 1);
+    assert_eq!(e.get_metadata().farmer_sponsor_pool.0, 0);
+    assert_user_seed_info(e.get_farmer_seed(&users.alice, &seed_id), to_yocto("10"), 0, 0, 0, 0);
+
+    // paid by register fee of the seed
+    e.modify_seed_register_fee(&e.owner, &seed_id, to_yocto("1"), 1).assert_success();
+    e.mft_stake_free_seed(&users.bob, &token_id, to_yocto("10")).assert_success();
+    assert_eq!(e.get_metadata().farmer_count.0, 2);
+    assert_user_seed_info(e.get_farmer_seed(&users.bob, &seed_id), to_yocto("9"), 0, 0, 0, 0);
+    assert_eq!(e.list_seeds_register_fee().get(&seed_id).unwrap().0, to_yocto("1"));
+    assert!(e.list_slashed().is_empty());
+    assert_eq!(e.storage_balance_of(&users.bob)["total"], "0");
+
+    // registration fee of auto registered farmer goes back to sponsor pool
+    assert_eq!(true, e.unlock_and_withdraw_seed(&users.alice, &seed_id, 0, to_yocto("10")).unwrap_json::<bool>());
+    assert_eq!(e.storage_unregister(&users.alice, 1).unwrap_json::<bool>(), true);
+    assert_eq!(e.get_metadata().farmer_sponsor_pool.0, to_yocto("0.1"));
+
+    assert_err!(
+        e.withdraw_farmer_sponsor_pool(&users.operator, to_yocto("0.1"), 1),
+        E002_NOT_ALLOWED
+    );
+    e.withdraw_farmer_sponsor_pool(&e.owner, to_yocto("0.1"), 1).assert_success();
+    assert_eq!(e.get_metadata().farmer_sponsor_pool.0, 0);
+
+    // registration fee paid in seed token does not go to sponsor pool
+    assert_eq!(true, e.unlock_and_withdraw_seed(&users.bob, &seed_id, 0, to_yocto("9")).unwrap_json::<bool>());
+    assert_eq!(e.storage_unregister(&users.bob, 1).unwrap_json::<bool>(), true);
+    assert_eq!(e.get_metadata().farmer_sponsor_pool.0, 0);
+    assert_eq!(e.get_metadata().farmer_count.0, 0);
+
+    // registration fee paid in seed token is withdrawn by owner
+    assert_err!(
+        e.withdraw_seed_register_fee(&users.bob, &seed_id, 1),
+        E002_NOT_ALLOWED
+    );
+    // failed transfer keeps the fee
+    e.withdraw_seed_register_fee(&e.owner, &seed_id, 1);
+    assert_eq!(e.list_seeds_register_fee().get(&seed_id).unwrap().0, to_yocto("1"));
+    e.mft_storage_deposit(&token_id, &e.owner);
+    e.withdraw_seed_register_fee(&e.owner, &seed_id, 1).assert_success();
+    assert_eq!(e.mft_balance_of(&e.owner, &token_id), to_yocto("1"));
+    assert!(e.list_seeds_register_fee().is_empty());
+    assert_err!(
+        e.withdraw_seed_register_fee(&e.owner, &seed_id, 1),
+        E101_INSUFFICIENT_BALANCE
+    );
+
+    // removing shadow never registers the farmer
+    let lending = &users.dude;
+    e.set_shadow_provider(&e.owner, lending, ShadowProvider {
+        shadow_prefix: "shadow_lending-".to_string(),
+        rule: ShadowSeedRule::MftPool { token_id: e.multi_fungible_token_contract.user_account.account_id() },
+        allowed_seeds: vec![seed_id.clone()],
+    }, 1).assert_success();
+    e.deposit_farmer_sponsor_pool(&users.operator, to_yocto("0.1")).assert_success();
+    assert_err!(
+        e.on_remove_shadow(lending, &users.farmer1, "shadow_lending-0", to_yocto("1"), ""),
+        E100_ACC_NOT_REGISTERED
+    );
+    assert_eq!(e.get_metadata().farmer_count.0, 0);
+    assert_eq!(e.get_metadata().farmer_sponsor_pool.0, to_yocto("0.1"));
+}
+
+#[test]
+fn test_reclaim_storage(){
+    let e = init_env();
+    let users = Users::init(&e);
+
+    e.storage_deposit(&users.alice, &users.bob, to_yocto("0.1")).assert_success();
+    // sponsor only pays the registration fee, the excess is refunded
+    e.storage_deposit(&users.alice, &users.charlie, to_yocto("0.5")).assert_success();
+    assert_eq!(e.storage_balance_of(&users.charlie)["total"], to_yocto("0.1").to_string());
+    e.storage_deposit(&users.dude, &users.dude, to_yocto("0.1")).assert_success();
+    assert_eq!(e.list_sponsored_farmers(&users.alice), vec![users.bob.account_id(), users.charlie.account_id()]);
+    assert!(e.list_sponsored_farmers(&users.dude).is_empty());
+
+    // bob tops up his own storage, top up from sponsor is refunded
+    e.storage_deposit(&users.bob, &users.bob, to_yocto("0.1")).assert_success();
+    let sponsor_balance_before = users.alice.account().unwrap().amount;
+    e.storage_deposit(&users.alice, &users.bob, to_yocto("0.1")).assert_success();
+    assert!(sponsor_balance_before - users.alice.account().unwrap().amount < to_yocto("0.01"));
+    assert_eq!(e.storage_balance_of(&users.bob)["total"], to_yocto("0.2").to_string());
+
+    // error scene
+    // 1 : Requires attached deposit of exactly 1 yoctoNEAR
+    assert_err!(
+        e.reclaim_storage(&users.alice, &users.bob, 0),
+        "Requires attached deposit of exactly 1 yoctoNEAR"
+    );
+
+    // 2 : E002_NOT_ALLOWED
+    assert_err!(
+        e.reclaim_storage(&users.charlie, &users.bob, 1),
+        E002_NOT_ALLOWED
+    );
+
+    // success
+    let sponsor_balance_before = users.alice.account().unwrap().amount;
+    let farmer_balance_before = users.bob.account().unwrap().amount;
+    e.reclaim_storage(&users.alice, &users.bob, 1).assert_success();
+    let sponsor_balance_after = users.alice.account().unwrap().amount;
+    let farmer_balance_after = users.bob.account().unwrap().amount;
+    assert!(sponsor_balance_after - sponsor_balance_before > to_yocto("0.09"));
+    assert!(sponsor_balance_after - sponsor_balance_before < to_yocto("0.1"));
+    assert_eq!(farmer_balance_after - farmer_balance_before, to_yocto("0.1"));
+    assert_eq!(e.list_sponsored_farmers(&users.alice), vec![users.charlie.account_id()]);
+    assert_eq!(e.get_metadata().farmer_count.0, 2);
+
+    // 3 : E100_ACC_NOT_REGISTERED
+    assert_err!(
+        e.reclaim_storage(&users.alice, &users.bob, 1),
+        E100_ACC_NOT_REGISTERED
+    );
+}
@@ -57,6 +57,45 @@ fn test_reward(){
     assert_farm_detail(e.get_farm(&farm_id), to_yocto("100"), start_at, 0, 0, 0, 0, Some(FarmStatus::Created));
 }
 
+#[test]
+fn test_create_farm_and_deposit(){
+    let e = init_env();
+    let users = Users::init(&e);
+    let tokens = Tokens::init(&e);
+
+    let inner_id = "0".to_string();
+    let seed_id = e.mft_seed_id(&inner_id);
+    let farm_id = format!("{}#{}", seed_id, 0);
+
+    e.create_seed(&e.owner, &seed_id, TOKEN_DECIMALS as u32, None, None).assert_success();
+    e.ft_mint(&tokens.nref, &users.operator, to_yocto("100"));
+
+    let start_at = e.current_time();
+
+    // error scene 
+    // 1 : E002_NOT_ALLOWED
+    assert_err!(
+        e.create_farm_and_deposit(&tokens.nref, &users.operator, to_yocto("100"), &seed_id, to_sec(start_at), to_yocto("10")),
+        E002_NOT_ALLOWED
+    );
+    assert_eq!(e.ft_balance_of(&tokens.nref, &users.operator), to_yocto("100"));
+
+    e.extend_operators(&e.owner, vec![&users.operator], 1).assert_success();
+
+    // 2 : E301_SEED_NOT_EXIST
+    assert_err!(
+        e.create_farm_and_deposit(&tokens.nref, &users.operator, to_yocto("100"), &"seed_id".to_string(), to_sec(start_at), to_yocto("10")),
+        E301_SEED_NOT_EXIST
+    );
+
+    // success
+    e.create_farm_and_deposit(&tokens.nref, &users.operator, to_yocto("100"), &seed_id, to_sec(start_at), to_yocto("10")).assert_success();
+    assert_eq!(e.ft_balance_of(&tokens.nref, &users.operator), 0);
+    assert_seed(e.get_seed(&seed_id), &seed_id, TOKEN_DECIMALS as u32, 1, 0, 0, MIN_SEED_DEPOSIT, DEFAULT_SEED_SLASH_RATE, DEFAULT_SEED_MIN_LOCKING_DURATION_SEC);
+    assert_farm_info(e.get_farm(&farm_id), &farm_id, &tokens.nref, to_sec(start_at), to_yocto("10"));
+    assert_farm_detail(e.get_farm(&farm_id), to_yocto("100"), start_at, 0, 0, 0, 0, Some(FarmStatus::Created));
+}
+
 #[test]
 fn test_free(){
     let e = init_env();
@@ -175,4 +214,43 @@ fn test_lock(){
     // append success
     e.mft_stake_lock_seed(&token_id, &users.farmer1, to_yocto("50"), DEFAULT_MAX_LOCKING_DURATION_SEC);
     assert_user_seed_info(e.get_farmer_seed(&users.farmer1, &seed_id), 0, to_yocto("100"), to_yocto("200"), e.current_time() + to_nano(DEFAULT_MAX_LOCKING_DURATION_SEC), DEFAULT_MAX_LOCKING_DURATION_SEC);
-}
\ No newline at end of file
+}
+#[test]
+fn test_mt_stake_and_withdraw(){
+    let e = init_env();
+    let users = Users::init(&e);
+    let mt = init_mt(&e, &account_id("mt.near"));
+
+    let token_id = "0".to_string();
+    let seed_id = e.mt_seed_id(&mt, &token_id);
+
+    println!("> create_seed at : {}", e.current_time());
+    e.create_seed(&e.owner, &seed_id, TOKEN_DECIMALS as u32, None, None).assert_success();
+
+    println!("> farmer1 mint mt at : {}", e.current_time());
+    e.mt_mint(&mt, &token_id, &users.farmer1, to_yocto("100"));
+    assert_eq!(e.mt_balance_of(&mt, &users.farmer1, &token_id), to_yocto("100"));
+    e.storage_deposit_self_to_farming(&users.farmer1).assert_success();
+
+    // error scene 
+    // 1 : E608_MT_CAN_NOT_BE_REWARD
+    assert_err!(
+        e.mt_transfer_call(&mt, &users.farmer1, &token_id, to_yocto("10"), format!("{{\"Reward\": {{\"farm_id\": \"{}#0\"}}}}", seed_id)),
+        E608_MT_CAN_NOT_BE_REWARD
+    );
+    assert_eq!(e.mt_balance_of(&mt, &users.farmer1, &token_id), to_yocto("100"));
+
+    // success
+    println!("> farmer1 stake_free_seed at : {}", e.current_time());
+    e.mt_stake_free_seed(&mt, &users.farmer1, &token_id, to_yocto("100")).assert_success();
+    assert_user_seed_info(e.get_farmer_seed(&users.farmer1, &seed_id), to_yocto("100"), 0, 0, 0, 0);
+    assert_eq!(e.mt_balance_of(&mt, &users.farmer1, &token_id), 0);
+    assert_eq!(e.mt_balance_of(&mt, &e.farming_contract.user_account, &token_id), to_yocto("100"));
+
+    println!("> farmer1 withdraw seed through mt_transfer at : {}", e.current_time());
+    e.unlock_and_withdraw_seed(&users.farmer1, &seed_id, 0, to_yocto("40")).assert_success();
+    assert_user_seed_info(e.get_farmer_seed(&users.farmer1, &seed_id), to_yocto("60"), 0, 0, 0, 0);
+    assert_eq!(e.mt_balance_of(&mt, &users.farmer1, &token_id), to_yocto("40"));
+    assert_eq!(e.mt_balance_of(&mt, &e.farming_contract.user_account, &token_id), to_yocto("60"));
+    assert!(e.get_farmer_lostfound(&users.farmer1).is_empty());
+}
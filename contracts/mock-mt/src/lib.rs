@@ -0,0 +1,97 @@
+use near_sdk::{
+    borsh::{self, BorshDeserialize, BorshSerialize},
+    collections::LookupMap,
+    json_types::U128,
+    near_bindgen, Balance, AccountId, PanicOnDefault, BorshStorageKey, require,
+};
+
+mod mt;
+
+#[derive(BorshStorageKey, BorshSerialize)]
+pub(crate) enum StorageKey {
+    Tokens,
+    Accounts {token_id: String},
+}
+
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct Token {
+    pub accounts: LookupMap<AccountId, Balance>,
+    pub total_supply: Balance,
+}
+
+#[near_bindgen]
+#[derive(BorshSerialize, BorshDeserialize, PanicOnDefault)]
+pub struct Contract {
+    tokens: LookupMap<String, Token>,
+}
+
+#[near_bindgen]
+impl Contract {
+    #[init]
+    pub fn new() -> Self {
+        Self {
+            tokens: LookupMap::new(StorageKey::Tokens),
+        }
+    }
+
+    pub fn mint(&mut self, token_id: String, account_id: AccountId, amount: U128) {
+        let mut token = self.internal_get_token(&token_id);
+
+        let new_amount = amount.0 + token.accounts.get(&account_id).unwrap_or_default();
+        token.accounts.insert(&account_id, &(new_amount));
+        token.total_supply += amount.0;
+
+        self.tokens.insert(&token_id, &token);
+    }
+
+    pub fn burn(&mut self, token_id: String, account_id: AccountId, amount: U128) {
+        let mut token = self.internal_get_token(&token_id);
+        let total = token.accounts.get(&account_id).unwrap_or_default();
+        require!(total >= amount.0, "NOT_ENOUGH_BALANCE");
+
+        token.accounts.insert(&account_id, &(total - amount.0));
+        token.total_supply -= amount.0;
+
+        self.tokens.insert(&token_id, &token);
+    }
+}
+
+impl Contract {
+    fn internal_get_token(&self, token_id: &String) -> Token {
+        self.tokens.get(token_id).unwrap_or_else(|| {
+            Token {
+                accounts: LookupMap::new(StorageKey::Accounts {
+                    token_id: token_id.clone(),
+                }),
+                total_supply: 0,
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use near_sdk::test_utils::{accounts, VMContextBuilder};
+    use near_sdk::testing_env;
+    use super::*;
+
+    #[test]
+    fn test_basics() {
+        let mut context = VMContextBuilder::new();
+        testing_env!(context.build());
+        let mut contract = Contract::new();
+
+        contract.mint(String::from("0"), accounts(0), 1_000_000.into());
+        assert_eq!(contract.mt_balance_of(accounts(0), String::from("0")), 1_000_000.into());
+
+        testing_env!(context
+            .attached_deposit(1)
+            .predecessor_account_id(accounts(0))
+            .build());
+        contract.mt_transfer(accounts(1), String::from("0"), 1_000.into(), None, None);
+        assert_eq!(contract.mt_balance_of(accounts(1), String::from("0")), 1_000.into());
+
+        contract.burn(String::from("0"), accounts(1), 500.into());
+        assert_eq!(contract.mt_balance_of(accounts(1), String::from("0")), 500.into());
+    }
+}
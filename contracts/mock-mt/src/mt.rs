@@ -0,0 +1,150 @@
+use crate::*;
+use near_sdk::serde_json::json;
+use near_sdk::{env, ext_contract, PromiseOrValue, assert_one_yocto, Gas, PromiseResult, log, Promise};
+
+pub const NO_DEPOSIT: Balance = 0;
+pub const TGAS: u64 = 1_000_000_000_000;
+const GAS_FOR_RESOLVE_TRANSFER: Gas = Gas(10 * TGAS);
+const GAS_FOR_MT_TRANSFER_CALL: Gas = Gas(30 * TGAS);
+
+#[ext_contract(ext_self)]
+trait MTTokenResolver {
+    fn mt_resolve_transfer(
+        &mut self,
+        previous_owner_ids: Vec<AccountId>,
+        receiver_id: AccountId,
+        token_ids: Vec<String>,
+        amounts: Vec<U128>,
+    ) -> Vec<U128>;
+}
+
+#[near_bindgen]
+impl Contract {
+
+    pub fn mt_balance_of(&self, account_id: AccountId, token_id: String) -> U128 {
+        self.internal_mt_balance(&token_id, &account_id).into()
+    }
+
+    pub fn mt_supply(&self, token_id: String) -> Option<U128> {
+        self.tokens.get(&token_id).map(|token| token.total_supply.into())
+    }
+
+    #[payable]
+    pub fn mt_transfer(
+        &mut self,
+        receiver_id: AccountId,
+        token_id: String,
+        amount: U128,
+        approval: Option<(AccountId, u64)>,
+        memo: Option<String>,
+    ) {
+        assert_one_yocto();
+        require!(approval.is_none(), "APPROVAL_NOT_SUPPORTED");
+        let sender_id = env::predecessor_account_id();
+        self.internal_mt_transfer(&token_id, &sender_id, &receiver_id, amount.0, memo);
+    }
+
+    #[payable]
+    pub fn mt_transfer_call(
+        &mut self,
+        receiver_id: AccountId,
+        token_id: String,
+        amount: U128,
+        approval: Option<(AccountId, u64)>,
+        memo: Option<String>,
+        msg: String,
+    ) -> PromiseOrValue<Vec<U128>> {
+        assert_one_yocto();
+        require!(approval.is_none(), "APPROVAL_NOT_SUPPORTED");
+        let sender_id = env::predecessor_account_id();
+        self.internal_mt_transfer(&token_id, &sender_id, &receiver_id, amount.0, memo);
+
+        // mt_on_transfer has too many args for ext_contract, so call it by raw json
+        Promise::new(receiver_id.clone()).function_call(
+            "mt_on_transfer".to_string(),
+            json!({
+                "sender_id": sender_id,
+                "previous_owner_ids": vec![sender_id.clone()],
+                "token_ids": vec![token_id.clone()],
+                "amounts": vec![amount],
+                "msg": msg,
+            }).to_string().into_bytes(),
+            NO_DEPOSIT,
+            env::prepaid_gas() - GAS_FOR_MT_TRANSFER_CALL - GAS_FOR_RESOLVE_TRANSFER,
+        )
+        .then(ext_self::mt_resolve_transfer(
+            vec![sender_id],
+            receiver_id,
+            vec![token_id],
+            vec![amount],
+            env::current_account_id(),
+            NO_DEPOSIT,
+            GAS_FOR_RESOLVE_TRANSFER,
+        ))
+        .into()
+    }
+
+    /// Returns how much of each token was kept by the receiver.
+    #[private]
+    pub fn mt_resolve_transfer(
+        &mut self,
+        previous_owner_ids: Vec<AccountId>,
+        receiver_id: AccountId,
+        token_ids: Vec<String>,
+        amounts: Vec<U128>,
+    ) -> Vec<U128> {
+        let unused_amounts: Vec<u128> = match env::promise_result(0) {
+            PromiseResult::NotReady => unreachable!(),
+            PromiseResult::Successful(value) => {
+                match near_sdk::serde_json::from_slice::<Vec<U128>>(&value) {
+                    Ok(unused_amounts) if unused_amounts.len() == amounts.len() => unused_amounts
+                        .iter()
+                        .zip(amounts.iter())
+                        .map(|(unused_amount, amount)| std::cmp::min(amount.0, unused_amount.0))
+                        .collect(),
+                    _ => amounts.iter().map(|amount| amount.0).collect(),
+                }
+            }
+            PromiseResult::Failed => amounts.iter().map(|amount| amount.0).collect(),
+        };
+        let mut used_amounts = vec![];
+        for (((previous_owner_id, token_id), amount), unused_amount) in previous_owner_ids.iter()
+            .zip(token_ids.iter())
+            .zip(amounts.iter())
+            .zip(unused_amounts)
+        {
+            let receiver_balance = self.internal_mt_balance(token_id, &receiver_id);
+            let refund_amount = std::cmp::min(receiver_balance, unused_amount);
+            if refund_amount > 0 {
+                self.internal_mt_transfer(token_id, &receiver_id, previous_owner_id, refund_amount, None);
+            }
+            used_amounts.push(U128(amount.0 - refund_amount));
+        }
+        used_amounts
+    }
+
+}
+
+impl Contract {
+    fn internal_mt_balance(&self, token_id: &String, account_id: &AccountId) -> u128 {
+        let token = self.tokens.get(token_id).expect("ERR_TOKEN_NOT_EXIST");
+        token.accounts.get(account_id).unwrap_or_default()
+    }
+
+    fn internal_mt_transfer(&mut self, token_id: &String, sender_id: &AccountId, receiver_id: &AccountId, amount: u128, memo: Option<String>) {
+        require!(sender_id != receiver_id, "SENDER_IS_RECEIVER");
+        let mut token = self.tokens.get(token_id).expect("ERR_TOKEN_NOT_EXIST");
+        let prev_sender_amount = token.accounts.get(sender_id).unwrap_or_default();
+        require!(prev_sender_amount >= amount, "NOT_ENOUGH_BALANCE");
+        let prev_receiver_amount = token.accounts.get(receiver_id).unwrap_or_default();
+
+        token.accounts.insert(sender_id, &(prev_sender_amount - amount));
+        token.accounts.insert(receiver_id, &(prev_receiver_amount + amount));
+
+        self.tokens.insert(token_id, &token);
+
+        if let Some(content) = memo {
+            log!("mt_transfer memo: {}", content);
+        }
+    }
+}
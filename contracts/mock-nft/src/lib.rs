@@ -0,0 +1,153 @@
+use near_contract_standards::non_fungible_token::core::{
+    NonFungibleTokenCore, NonFungibleTokenResolver,
+};
+use near_contract_standards::non_fungible_token::metadata::TokenMetadata;
+use near_contract_standards::non_fungible_token::{NonFungibleToken, Token, TokenId};
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::LookupMap;
+use near_sdk::json_types::U128;
+use near_sdk::{
+    env, near_bindgen, require, AccountId, Balance, BorshStorageKey, PanicOnDefault,
+    PromiseOrValue,
+};
+use std::collections::HashMap;
+
+#[derive(BorshStorageKey, BorshSerialize)]
+pub(crate) enum StorageKey {
+    NonFungibleToken,
+    TokenMetadata,
+    Liquidities,
+}
+
+/// Mock nft whose tokens carry a liquidity value,
+/// exposed both by `get_liquidity` and by `{"liquidity": "<amount>"}` in metadata.extra.
+#[near_bindgen]
+#[derive(BorshSerialize, BorshDeserialize, PanicOnDefault)]
+pub struct Contract {
+    tokens: NonFungibleToken,
+    liquidities: LookupMap<TokenId, Balance>,
+    /// when true, nft_transfer panics to simulate a failed withdraw
+    transfer_paused: bool,
+}
+
+#[near_bindgen]
+impl Contract {
+    #[init]
+    pub fn new() -> Self {
+        Self {
+            tokens: NonFungibleToken::new(
+                StorageKey::NonFungibleToken,
+                env::current_account_id(),
+                Some(StorageKey::TokenMetadata),
+                None::<StorageKey>,
+                None::<StorageKey>,
+            ),
+            liquidities: LookupMap::new(StorageKey::Liquidities),
+            transfer_paused: false,
+        }
+    }
+
+    pub fn mint(&mut self, token_id: TokenId, owner_id: AccountId, liquidity: U128) -> Token {
+        self.liquidities.insert(&token_id, &liquidity.0);
+        let metadata = TokenMetadata {
+            title: Some(token_id.clone()),
+            description: None,
+            media: None,
+            media_hash: None,
+            copies: None,
+            issued_at: None,
+            expires_at: None,
+            starts_at: None,
+            updated_at: None,
+            extra: Some(format!("{{\"liquidity\": \"{}\"}}", liquidity.0)),
+            reference: None,
+            reference_hash: None,
+        };
+        self.tokens.internal_mint_with_refund(token_id, owner_id, Some(metadata), None)
+    }
+
+    pub fn get_liquidity(&self, token_id: TokenId) -> U128 {
+        self.liquidities.get(&token_id).expect("ERR_TOKEN_NOT_EXIST").into()
+    }
+
+    pub fn set_transfer_paused(&mut self, paused: bool) {
+        self.transfer_paused = paused;
+    }
+}
+
+#[near_bindgen]
+impl NonFungibleTokenCore for Contract {
+    #[payable]
+    fn nft_transfer(
+        &mut self,
+        receiver_id: AccountId,
+        token_id: TokenId,
+        approval_id: Option<u64>,
+        memo: Option<String>,
+    ) {
+        require!(!self.transfer_paused, "ERR_TRANSFER_PAUSED");
+        self.tokens.nft_transfer(receiver_id, token_id, approval_id, memo)
+    }
+
+    #[payable]
+    fn nft_transfer_call(
+        &mut self,
+        receiver_id: AccountId,
+        token_id: TokenId,
+        approval_id: Option<u64>,
+        memo: Option<String>,
+        msg: String,
+    ) -> PromiseOrValue<bool> {
+        self.tokens.nft_transfer_call(receiver_id, token_id, approval_id, memo, msg)
+    }
+
+    fn nft_token(&self, token_id: TokenId) -> Option<Token> {
+        self.tokens.nft_token(token_id)
+    }
+}
+
+#[near_bindgen]
+impl NonFungibleTokenResolver for Contract {
+    #[private]
+    fn nft_resolve_transfer(
+        &mut self,
+        previous_owner_id: AccountId,
+        receiver_id: AccountId,
+        token_id: TokenId,
+        approved_account_ids: Option<HashMap<AccountId, u64>>,
+    ) -> bool {
+        self.tokens.nft_resolve_transfer(
+            previous_owner_id,
+            receiver_id,
+            token_id,
+            approved_account_ids,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use near_sdk::test_utils::{accounts, VMContextBuilder};
+    use near_sdk::testing_env;
+    use super::*;
+
+    #[test]
+    fn test_basics() {
+        let mut context = VMContextBuilder::new();
+        testing_env!(context.build());
+        let mut contract = Contract::new();
+
+        contract.mint(String::from("1"), accounts(0), 1_000.into());
+        assert_eq!(contract.get_liquidity(String::from("1")), 1_000.into());
+        let token = contract.nft_token(String::from("1")).unwrap();
+        assert_eq!(token.owner_id, accounts(0));
+        assert_eq!(token.metadata.unwrap().extra.unwrap(), "{\"liquidity\": \"1000\"}");
+
+        testing_env!(context
+            .attached_deposit(1)
+            .predecessor_account_id(accounts(0))
+            .build());
+        contract.nft_transfer(accounts(1), String::from("1"), None, None);
+        assert_eq!(contract.nft_token(String::from("1")).unwrap().owner_id, accounts(1));
+    }
+}